@@ -0,0 +1,437 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{DimensionMismatchError, Grid};
+
+/// A row vector that is broadcast across all rows of a grid in arithmetic operations.
+///
+/// Its length must match the width of the grid, else the operation panics.
+/// The methods like [`Grid::try_add_row`] return an error instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZero;
+/// use grid2d::{Grid, Row};
+///
+/// let grid = Grid::try_from(([1, 2, 3, 4, 5, 6], NonZero::new(3).unwrap())).unwrap();
+/// let shifted = &grid + Row(&[10, 20, 30]);
+///
+/// assert_eq!(shifted.as_ref(), [11, 22, 33, 14, 25, 36]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Row<'a, T>(pub &'a [T]);
+
+/// A column vector that is broadcast across all columns of a grid in arithmetic operations.
+///
+/// Its length must match the height of the grid, else the operation panics.
+/// The methods like [`Grid::try_add_column`] return an error instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZero;
+/// use grid2d::{Column, Grid};
+///
+/// let mut grid = Grid::try_from(([1, 2, 3, 4, 5, 6], NonZero::new(3).unwrap())).unwrap();
+/// grid *= Column(&[2, 10]);
+///
+/// assert_eq!(grid.as_ref(), [2, 4, 6, 40, 50, 60]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Column<'a, T>(pub &'a [T]);
+
+impl<T> Grid<T> {
+    /// Combines each item with the item of the row vector in the same column.
+    fn broadcast_row(
+        &mut self,
+        row: &[T],
+        operation: impl Fn(&mut T, &T),
+    ) -> Result<(), DimensionMismatchError> {
+        let expected = (self.width().get(), self.height().get());
+        let found = (row.len(), expected.1);
+
+        if found != expected {
+            return Err(DimensionMismatchError::new(expected, found));
+        }
+
+        for items in self.as_mut().chunks_mut(row.len()) {
+            for (item, value) in items.iter_mut().zip(row) {
+                operation(item, value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Combines each item with the item of the column vector in the same row.
+    fn broadcast_column(
+        &mut self,
+        column: &[T],
+        operation: impl Fn(&mut T, &T),
+    ) -> Result<(), DimensionMismatchError> {
+        let expected = (self.width().get(), self.height().get());
+        let found = (expected.0, column.len());
+
+        if found != expected {
+            return Err(DimensionMismatchError::new(expected, found));
+        }
+
+        let width = self.width().get();
+
+        for (items, value) in self.as_mut().chunks_mut(width).zip(column) {
+            for item in items {
+                operation(item, value);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_operator {
+    (
+        $operator:ident,
+        $method:ident,
+        $assign:ident,
+        $assign_method:ident,
+        $try:ident,
+        $try_row:ident,
+        $try_column:ident,
+        $name:literal,
+        $symbol:literal,
+        $grid_result:literal,
+        $scalar_result:literal,
+        $row_result:literal,
+        $column_result:literal
+    ) => {
+        impl<T> Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            #[doc = concat!("Returns the element-wise ", $name, " of both grids.")]
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DimensionMismatchError`] if the grids differ in width or height.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::num::NonZero;
+            /// use grid2d::Grid;
+            ///
+            /// let grid = Grid::try_from(([6, 8, 10, 12], NonZero::new(2).unwrap())).unwrap();
+            /// let other = Grid::try_from(([3, 2, 5, 4], NonZero::new(2).unwrap())).unwrap();
+            /// let column = Grid::try_from(([1, 2], NonZero::new(1).unwrap())).unwrap();
+            ///
+            #[doc = concat!("assert_eq!(grid.", stringify!($try), "(&other).unwrap().as_ref(), ", $grid_result, ");")]
+            #[doc = concat!("assert!(grid.", stringify!($try), "(&column).is_err());")]
+            /// ```
+            pub fn $try(&self, other: &Self) -> Result<Self, DimensionMismatchError> {
+                self.zip_with(other, |left, right| left.$method(*right))
+            }
+
+            #[doc = concat!("Returns the element-wise ", $name, " of the grid and the row broadcast across all rows.")]
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DimensionMismatchError`] if the length of the row differs from the width of the grid.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::num::NonZero;
+            /// use grid2d::{Grid, Row};
+            ///
+            /// let grid = Grid::try_from(([6, 8, 10, 12], NonZero::new(2).unwrap())).unwrap();
+            ///
+            #[doc = concat!("assert_eq!(grid.", stringify!($try_row), "(Row(&[2, 4])).unwrap().as_ref(), ", $row_result, ");")]
+            #[doc = concat!("assert!(grid.", stringify!($try_row), "(Row(&[2, 4, 6])).is_err());")]
+            /// ```
+            pub fn $try_row(&self, row: Row<'_, T>) -> Result<Self, DimensionMismatchError> {
+                let mut result = self.clone();
+                result.broadcast_row(row.0, |item, value| *item = item.$method(*value))?;
+                Ok(result)
+            }
+
+            #[doc = concat!("Returns the element-wise ", $name, " of the grid and the column broadcast across all columns.")]
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DimensionMismatchError`] if the length of the column differs from the height of the grid.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::num::NonZero;
+            /// use grid2d::{Column, Grid};
+            ///
+            /// let grid = Grid::try_from(([6, 8, 10, 12], NonZero::new(2).unwrap())).unwrap();
+            ///
+            #[doc = concat!("assert_eq!(grid.", stringify!($try_column), "(Column(&[1, 2])).unwrap().as_ref(), ", $column_result, ");")]
+            #[doc = concat!("assert!(grid.", stringify!($try_column), "(Column(&[1])).is_err());")]
+            /// ```
+            pub fn $try_column(&self, column: Column<'_, T>) -> Result<Self, DimensionMismatchError> {
+                let mut result = self.clone();
+                result.broadcast_column(column.0, |item, value| *item = item.$method(*value))?;
+                Ok(result)
+            }
+        }
+
+        impl<T> $operator<&Grid<T>> for &Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Grid<T>;
+
+            fn $method(self, rhs: &Grid<T>) -> Self::Output {
+                self.$try(rhs)
+                    .expect("dimensions of grids do not match")
+            }
+        }
+
+        impl<T> $operator<&Self> for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Self;
+
+            fn $method(mut self, rhs: &Self) -> Self::Output {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl<T> $operator for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<T> $assign<&Self> for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            fn $assign_method(&mut self, rhs: &Self) {
+                self.check_dimensions(rhs)
+                    .expect("dimensions of grids do not match");
+
+                for (item, value) in self.iter_mut().zip(rhs.iter()) {
+                    *item = item.$method(*value);
+                }
+            }
+        }
+
+        impl<T> $assign for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            fn $assign_method(&mut self, rhs: Self) {
+                self.$assign_method(&rhs);
+            }
+        }
+
+        impl<T> $operator<T> for &Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Grid<T>;
+
+            #[doc = concat!("Returns a grid with the ", $name, " of each item and the scalar.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::num::NonZero;
+            /// use grid2d::Grid;
+            ///
+            /// let grid = Grid::try_from(([6, 8, 10, 12], NonZero::new(2).unwrap())).unwrap();
+            ///
+            #[doc = concat!("assert_eq!((&grid ", $symbol, " 2).as_ref(), ", $scalar_result, ");")]
+            /// ```
+            fn $method(self, rhs: T) -> Self::Output {
+                self.map(|item| item.$method(rhs))
+            }
+        }
+
+        impl<T> $operator<T> for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Self;
+
+            #[doc = concat!("Replaces each item with its ", $name, " with the scalar.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::num::NonZero;
+            /// use grid2d::Grid;
+            ///
+            /// let grid = Grid::try_from(([6, 8, 10, 12], NonZero::new(2).unwrap())).unwrap();
+            ///
+            #[doc = concat!("assert_eq!((grid ", $symbol, " 2).as_ref(), ", $scalar_result, ");")]
+            /// ```
+            fn $method(mut self, rhs: T) -> Self::Output {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl<T> $assign<T> for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            #[doc = concat!("Replaces each item with its ", $name, " with the scalar.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::num::NonZero;
+            /// use grid2d::Grid;
+            ///
+            /// let mut grid = Grid::try_from(([6, 8, 10, 12], NonZero::new(2).unwrap())).unwrap();
+            #[doc = concat!("grid ", $symbol, "= 2;")]
+            ///
+            #[doc = concat!("assert_eq!(grid.as_ref(), ", $scalar_result, ");")]
+            /// ```
+            fn $assign_method(&mut self, rhs: T) {
+                for item in self.iter_mut() {
+                    *item = item.$method(rhs);
+                }
+            }
+        }
+
+        impl<T> $operator<Row<'_, T>> for &Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Grid<T>;
+
+            fn $method(self, rhs: Row<'_, T>) -> Self::Output {
+                self.clone().$method(rhs)
+            }
+        }
+
+        impl<T> $operator<Row<'_, T>> for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Row<'_, T>) -> Self::Output {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl<T> $assign<Row<'_, T>> for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            fn $assign_method(&mut self, rhs: Row<'_, T>) {
+                self.broadcast_row(rhs.0, |item, value| *item = item.$method(*value))
+                    .expect("length of row does not match width of grid");
+            }
+        }
+
+        impl<T> $operator<Column<'_, T>> for &Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Grid<T>;
+
+            fn $method(self, rhs: Column<'_, T>) -> Self::Output {
+                self.clone().$method(rhs)
+            }
+        }
+
+        impl<T> $operator<Column<'_, T>> for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Column<'_, T>) -> Self::Output {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl<T> $assign<Column<'_, T>> for Grid<T>
+        where
+            T: Copy + $operator<Output = T>,
+        {
+            fn $assign_method(&mut self, rhs: Column<'_, T>) {
+                self.broadcast_column(rhs.0, |item, value| *item = item.$method(*value))
+                    .expect("length of column does not match height of grid");
+            }
+        }
+    };
+}
+
+impl_operator!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    try_add,
+    try_add_row,
+    try_add_column,
+    "sum",
+    "+",
+    "[9, 10, 15, 16]",
+    "[8, 10, 12, 14]",
+    "[8, 12, 12, 16]",
+    "[7, 9, 12, 14]"
+);
+impl_operator!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    try_sub,
+    try_sub_row,
+    try_sub_column,
+    "difference",
+    "-",
+    "[3, 6, 5, 8]",
+    "[4, 6, 8, 10]",
+    "[4, 4, 8, 8]",
+    "[5, 7, 8, 10]"
+);
+impl_operator!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    try_mul,
+    try_mul_row,
+    try_mul_column,
+    "product",
+    "*",
+    "[18, 16, 50, 48]",
+    "[12, 16, 20, 24]",
+    "[12, 32, 20, 48]",
+    "[6, 8, 20, 24]"
+);
+impl_operator!(
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    try_div,
+    try_div_row,
+    try_div_column,
+    "quotient",
+    "/",
+    "[2, 4, 2, 3]",
+    "[3, 4, 5, 6]",
+    "[3, 2, 5, 3]",
+    "[6, 8, 5, 6]"
+);
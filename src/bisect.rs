@@ -0,0 +1,16 @@
+/// Returns the number of steps below `length` for which the monotonic predicate holds.
+pub fn partition_point(length: u128, predicate: impl Fn(u128) -> bool) -> u128 {
+    let (mut low, mut high) = (0, length);
+
+    while low < high {
+        let middle = low + (high - low) / 2;
+
+        if predicate(middle) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
@@ -0,0 +1,21 @@
+/// Converts an index or length into a float, which is only exact up to 2^53.
+#[expect(clippy::cast_precision_loss)]
+pub const fn to_f64(value: usize) -> f64 {
+    value as f64
+}
+
+/// Returns the index of the cell containing the given non-negative position.
+#[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn to_index(position: f64) -> usize {
+    position as usize
+}
+
+/// Converts an index or length into a signed integer that can also hold its negation and doubling.
+pub fn to_i128(value: usize) -> i128 {
+    i128::from(value as u64)
+}
+
+/// Converts an index or length into an unsigned integer that can also hold its square.
+pub fn to_u128(value: usize) -> u128 {
+    u128::from(value as u64)
+}
@@ -0,0 +1,43 @@
+use crate::Direction;
+
+/// Determines which cells count as adjacent to each other.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Connectivity {
+    /// Cells are adjacent if they share a side.
+    #[default]
+    Four,
+    /// Cells are adjacent if they share a side or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    /// Returns the directions towards the adjacent cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Connectivity, Direction};
+    ///
+    /// assert_eq!(Connectivity::Four.directions(), Direction::CARDINAL);
+    /// assert_eq!(Connectivity::Eight.directions().len(), 8);
+    /// ```
+    #[must_use]
+    pub const fn directions(self) -> &'static [Direction] {
+        match self {
+            Self::Four => &Direction::CARDINAL,
+            Self::Eight => &Direction::ALL,
+        }
+    }
+
+    /// Returns the connectivity of the background, given the connectivity of the foreground.
+    ///
+    /// Regions and the gaps between them are only separated consistently,
+    /// if one of them is four-connected and the other one is eight-connected.
+    #[must_use]
+    pub const fn dual(self) -> Self {
+        match self {
+            Self::Four => Self::Eight,
+            Self::Eight => Self::Four,
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::cast::to_f64;
+use crate::{Connectivity, Coordinate, Direction, Grid, Side};
+
+/// A polygon or polyline that outlines a region of a grid.
+///
+/// Contours are oriented so that the region lies to their right, with the y axis pointing downwards.
+/// Hence, outer boundaries run clockwise and the boundaries of holes run counter-clockwise.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Contour<P> {
+    points: Vec<P>,
+    closed: bool,
+}
+
+impl<P> Contour<P> {
+    /// Returns the points of the contour.
+    ///
+    /// The last point of a closed contour connects back to the first one, without being repeated.
+    #[must_use]
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    /// Returns true, if the contour is a closed polygon, else false.
+    ///
+    /// Contours may be open polylines if they run into the border of the grid.
+    #[must_use]
+    pub const fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Consumes the contour and returns its points.
+    #[must_use]
+    pub fn into_points(self) -> Vec<P> {
+        self.points
+    }
+}
+
+impl Contour<Coordinate> {
+    /// Returns the signed area enclosed by the contour.
+    ///
+    /// The area is positive for outer boundaries and negative for holes.
+    #[must_use]
+    pub fn signed_area(&self) -> f64 {
+        signed_area(
+            self.points
+                .iter()
+                .map(|point| [point.x(), point.y()].map(to_f64)),
+        )
+    }
+
+    /// Returns true, if the contour is the boundary of a hole, else false.
+    #[must_use]
+    pub fn is_hole(&self) -> bool {
+        self.signed_area() < 0.0
+    }
+}
+
+impl Contour<[f64; 2]> {
+    /// Returns the signed area enclosed by the contour, if it is closed.
+    ///
+    /// The area is positive for contours around higher values and negative for contours around lower values.
+    #[must_use]
+    pub fn signed_area(&self) -> Option<f64> {
+        self.closed
+            .then(|| signed_area(self.points.iter().copied()))
+    }
+
+    /// Returns true, if the contour is closed and encloses lower values than its surroundings, else false.
+    #[must_use]
+    pub fn is_hole(&self) -> bool {
+        self.signed_area().is_some_and(|area| area < 0.0)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Traces the boundaries of the regions of cells that match the predicate.
+    ///
+    /// The boundaries are closed polygons along the sides of the cells,
+    /// whose points are the corners where the boundary changes its direction.
+    /// The corner at `(x, y)` is the upper left corner of the cell at `(x, y)`.
+    ///
+    /// The boundary cells are found with Moore-neighbor tracing: the neighbors of each boundary cell
+    /// are swept clockwise, starting at the cell outside of the region that it was entered from,
+    /// until the next boundary cell is found. The tracing stops according to Jacob's stopping criterion,
+    /// once it enters the first boundary cell from the same direction again.
+    /// The polygon runs along the sides that face the neighbors outside of the region
+    /// which are swept over, so that regions that are only one cell wide are outlined exactly.
+    ///
+    /// The connectivity determines whether cells that only touch diagonally belong to the same boundary.
+    /// For [`Connectivity::Four`], a diagonal neighbor is only followed if it shares a side
+    /// with the next neighbor in the sweep that belongs to the region, too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Coordinate, Grid};
+    ///
+    /// let grid = Grid::try_from(("####.####".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let boundaries = grid.boundaries_where(Connectivity::Four, |cell| *cell == '#');
+    /// let points: Vec<Vec<_>> = boundaries
+    ///     .iter()
+    ///     .map(|boundary| boundary.points().iter().map(|point| (point.x(), point.y())).collect())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     points,
+    ///     [
+    ///         vec![(0, 0), (3, 0), (3, 3), (0, 3)],
+    ///         vec![(1, 1), (1, 2), (2, 2), (2, 1)],
+    ///     ]
+    /// );
+    /// assert!(!boundaries[0].is_hole());
+    /// assert!(boundaries[1].is_hole());
+    /// assert_eq!(boundaries[1].signed_area(), -1.0);
+    /// ```
+    pub fn boundaries_where(
+        &self,
+        connectivity: Connectivity,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Vec<Contour<Coordinate>> {
+        let is_inside = |cell: Coordinate, direction: Direction| {
+            cell.step(direction)
+                .and_then(|neighbor| self.get(neighbor))
+                .is_some_and(&predicate)
+        };
+        let mut traced = Grid::new(self.width(), self.height(), || 0_u8);
+        let mut contours = Vec::new();
+
+        for (coordinate, cell) in self.enumerate() {
+            if !predicate(cell) {
+                continue;
+            }
+
+            for side in Side::ALL {
+                let direction = Direction::from(side);
+
+                if traced[coordinate] & bit(side as usize) != 0 || is_inside(coordinate, direction)
+                {
+                    continue;
+                }
+
+                let mut sides = Vec::new();
+                let visit = |cell, backtrack, sides: &mut Vec<_>| {
+                    sweep(cell, backtrack, connectivity, is_inside, |side| {
+                        sides.push((cell, side));
+                    })
+                };
+
+                // Starting at an arbitrary side may skip sides that the boundary passes before it,
+                // so it is only traced from the state that the first sweep leads to.
+                if let Some(first) = visit(coordinate, direction, &mut sides) {
+                    sides.clear();
+                    let mut state = first;
+
+                    while let Some(next) = visit(state.0, state.1, &mut sides) {
+                        state = next;
+
+                        if state == first {
+                            break;
+                        }
+                    }
+                }
+
+                for (cell, side) in &sides {
+                    traced[*cell] |= bit(*side as usize);
+                }
+
+                contours.push(polygon(&sides));
+            }
+        }
+
+        contours.sort_unstable_by_key(|(start, _)| *start);
+        contours
+            .into_iter()
+            .map(|(_, points)| Contour {
+                points,
+                closed: true,
+            })
+            .collect()
+    }
+
+    /// Extracts the contour lines at the given level using the marching squares algorithm.
+    ///
+    /// The values of the cells are sampled at their centers, i.e. the value of the cell at `(x, y)`
+    /// is located at `(x + 0.5, y + 0.5)`. Values greater than or equal to the level count as inside.
+    /// Contour lines that run into the border of the sampled area are open polylines.
+    /// Ambiguous saddle points are resolved by the mean value of the four surrounding samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let grid = Grid::try_from(([0, 0, 0, 0, 4, 0, 0, 0, 0], NonZero::new(3).unwrap())).unwrap();
+    /// let contours = grid.isolines(2.0, |value| f64::from(*value));
+    ///
+    /// assert_eq!(contours.len(), 1);
+    /// assert!(contours[0].is_closed());
+    /// assert_eq!(contours[0].points(), [[1.0, 1.5], [1.5, 1.0], [2.0, 1.5], [1.5, 2.0]]);
+    /// assert_eq!(contours[0].signed_area(), Some(0.5));
+    ///
+    /// let slope = Grid::try_from(([0, 1, 2, 3, 0, 1, 2, 3], NonZero::new(4).unwrap())).unwrap();
+    /// let contours = slope.isolines(1.5, |value| f64::from(*value));
+    ///
+    /// assert!(!contours[0].is_closed());
+    /// assert_eq!(contours[0].points(), [[2.0, 1.5], [2.0, 0.5]]);
+    /// ```
+    pub fn isolines(&self, level: f64, value: impl Fn(&T) -> f64) -> Vec<Contour<[f64; 2]>> {
+        let width = self.width().get();
+        let height = self.height().get();
+        let samples: Vec<f64> = self.iter().map(value).collect();
+        let sample = |x: usize, y: usize| samples[y * width + x];
+        let horizontal = (width - 1) * height;
+        let crossings = horizontal + width * (height - 1);
+        let mut next = vec![usize::MAX; crossings];
+        let mut incoming = vec![false; crossings];
+
+        for y in 0..height.saturating_sub(1) {
+            for x in 0..width - 1 {
+                let corners = [
+                    sample(x, y),
+                    sample(x + 1, y),
+                    sample(x + 1, y + 1),
+                    sample(x, y + 1),
+                ];
+                let sides = [
+                    y * (width - 1) + x,
+                    horizontal + y * width + x + 1,
+                    (y + 1) * (width - 1) + x,
+                    horizontal + y * width + x,
+                ];
+                let mut found = [(0, false); 4];
+                let mut count = 0;
+
+                for (side, id) in sides.into_iter().enumerate() {
+                    let from = corners[side] >= level;
+
+                    if from != (corners[(side + 1) % 4] >= level) {
+                        found[count] = (id, from);
+                        count += 1;
+                    }
+                }
+
+                let found = &found[..count];
+                let connected = corners.iter().sum::<f64>() / 4.0 >= level;
+
+                for (index, &(id, exit)) in found.iter().enumerate() {
+                    if !exit {
+                        continue;
+                    }
+
+                    let enter = (1..count)
+                        .map(|step| {
+                            if connected {
+                                (index + step) % count
+                            } else {
+                                (index + count - step) % count
+                            }
+                        })
+                        .map(|other| found[other])
+                        .find(|(_, exit)| !exit);
+
+                    if let Some((target, _)) = enter {
+                        next[id] = target;
+                        incoming[target] = true;
+                    }
+                }
+            }
+        }
+
+        let point = |id: usize| {
+            let (start, end) = if id < horizontal {
+                let (x, y) = (id % (width - 1), id / (width - 1));
+                ((x, y), (x + 1, y))
+            } else {
+                let (x, y) = ((id - horizontal) % width, (id - horizontal) / width);
+                ((x, y), (x, y + 1))
+            };
+            let (from, to) = (sample(start.0, start.1), sample(end.0, end.1));
+            let t = (level - from) / (to - from);
+            [
+                to_f64(start.0) + t * to_f64(end.0 - start.0) + 0.5,
+                to_f64(start.1) + t * to_f64(end.1 - start.1) + 0.5,
+            ]
+        };
+        let mut visited = vec![false; crossings];
+        let mut contours = Vec::new();
+        let starts = (0..crossings)
+            .filter(|id| next[*id] != usize::MAX && !incoming[*id])
+            .chain(0..crossings);
+
+        for start in starts {
+            if visited[start] || next[start] == usize::MAX {
+                continue;
+            }
+
+            let mut points = Vec::new();
+            let mut current = start;
+
+            while !visited[current] {
+                visited[current] = true;
+                points.push(point(current));
+
+                if next[current] == usize::MAX {
+                    break;
+                }
+
+                current = next[current];
+            }
+
+            contours.push(Contour {
+                points,
+                closed: current == start && incoming[start],
+            });
+        }
+
+        contours
+    }
+}
+
+impl Grid<bool> {
+    /// Traces the boundaries of the regions of `true` cells.
+    ///
+    /// See [`Grid::boundaries_where`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::try_from(([true, false, false, true], NonZero::new(2).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.boundaries(Connectivity::Four).len(), 2);
+    /// assert_eq!(grid.boundaries(Connectivity::Eight).len(), 1);
+    /// assert_eq!(grid.boundaries(Connectivity::Eight)[0].points().len(), 8);
+    /// ```
+    #[must_use]
+    pub fn boundaries(&self, connectivity: Connectivity) -> Vec<Contour<Coordinate>> {
+        self.boundaries_where(connectivity, |cell| *cell)
+    }
+}
+
+/// Sweeps the neighbors of a boundary cell clockwise, starting with the given neighbor outside of the region.
+///
+/// Calls `on_side` with the sides of the cell facing the swept neighbors outside of the region.
+/// Returns the next boundary cell and the direction from it back to the last neighbor swept before,
+/// or `None` if the cell has no neighbors in the region.
+fn sweep(
+    cell: Coordinate,
+    backtrack: Direction,
+    connectivity: Connectivity,
+    is_inside: impl Fn(Coordinate, Direction) -> bool,
+    mut on_side: impl FnMut(Side),
+) -> Option<(Coordinate, Direction)> {
+    let mut direction = backtrack;
+
+    for step in 0..Direction::ALL.len() {
+        let follow = step > 0
+            && is_inside(cell, direction)
+            && (direction.is_cardinal()
+                || connectivity == Connectivity::Eight
+                || is_inside(cell, direction.turn_slightly_right()));
+
+        if follow {
+            let next = cell.step(direction)?;
+            let back = if direction.is_cardinal() {
+                direction.turn_left()
+            } else {
+                direction.turn_left().turn_slightly_left()
+            };
+            return Some((next, back));
+        }
+
+        if let Some(side) = direction.side() {
+            on_side(side);
+        }
+
+        direction = direction.turn_slightly_right();
+    }
+
+    None
+}
+
+/// Returns the corners of the polygon along the given sides of cells in order,
+/// together with the position of its first side, which is the upper left one.
+///
+/// The polygon starts at the first corner in row-major order, which sorts the contours by their position.
+fn polygon(sides: &[(Coordinate, Side)]) -> ((usize, usize, usize), Vec<Coordinate>) {
+    let mut moves: Vec<_> = sides
+        .iter()
+        .map(|(cell, side)| {
+            let (x, y) = (*cell).into();
+            let corner = match side {
+                Side::North => (x, y),
+                Side::East => (x + 1, y),
+                Side::South => (x + 1, y + 1),
+                Side::West => (x, y + 1),
+            };
+            (corner.1, corner.0, turn(*side as usize, 1))
+        })
+        .collect();
+    let first = (0..moves.len())
+        .min_by_key(|index| moves[*index])
+        .unwrap_or_default();
+    moves.rotate_left(first);
+    let previous = moves.last().map(|(_, _, direction)| *direction);
+    let points = moves
+        .iter()
+        .zip(
+            previous
+                .into_iter()
+                .chain(moves.iter().map(|(_, _, direction)| *direction)),
+        )
+        .filter(|((_, _, direction), previous)| direction != previous)
+        .map(|((y, x, _), _)| Coordinate::new(*x, *y))
+        .collect();
+    (moves.first().copied().unwrap_or_default(), points)
+}
+
+/// Returns the bit of the given direction, indexed like [`Side::ALL`].
+const fn bit(direction: usize) -> u8 {
+    1 << direction
+}
+
+/// Turns the direction, indexed like [`Side::ALL`], clockwise by the given amount of quarter turns.
+const fn turn(direction: usize, turns: usize) -> usize {
+    (direction + turns) % 4
+}
+
+/// Computes the signed area of a closed polygon with the shoelace formula.
+fn signed_area(points: impl Iterator<Item = [f64; 2]> + Clone) -> f64 {
+    let mut following = points.clone().cycle().skip(1);
+    points
+        .map(|[x, y]| {
+            let [next_x, next_y] = following.next().unwrap_or([x, y]);
+            x * next_y - next_x * y
+        })
+        .sum::<f64>()
+        / 2.0
+}
@@ -1,17 +1,44 @@
 use core::fmt::{self, Display, Formatter};
-use core::num::NonZero;
-use core::ops::Add;
+use core::num::{NonZero, TryFromIntError};
+use core::ops::{Add, Sub};
 use core::str::FromStr;
 
-use crate::CoordinateParseError;
+pub use self::notation::Notation;
+use crate::{Connectivity, CoordinateParseError, Direction, Grid, Offset};
+
+mod notation;
 
 /// Coordinate of a cell on a two-dimensional grid.
+///
+/// The component type `I` defaults to `usize`, which is what [`Grid`] uses for indexing.
+/// Smaller integer types can be used to store large amounts of coordinates compactly.
+/// They convert losslessly into and fallibly from other component types.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::{Coordinate, Coordinate16, Coordinate32};
+///
+/// assert_eq!(size_of::<Coordinate16>(), 4);
+///
+/// let compact = Coordinate16::from_components(3, 4);
+/// let coordinate: Coordinate = compact.into();
+/// assert_eq!(coordinate, Coordinate::new(3, 4));
+/// assert_eq!(Coordinate32::try_from(coordinate), Ok(Coordinate32::from_components(3, 4)));
+/// assert!(Coordinate16::try_from(Coordinate::new(70_000, 0)).is_err());
+/// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub struct Coordinate {
-    x: usize,
-    y: usize,
+pub struct Coordinate<I = usize> {
+    x: I,
+    y: I,
 }
 
+/// A coordinate with `u16` components.
+pub type Coordinate16 = Coordinate<u16>;
+
+/// A coordinate with `u32` components.
+pub type Coordinate32 = Coordinate<u32>;
+
 const NEIGHBOR_OFFSETS: [(isize, isize); 8] = [
     (-1, -1),
     (0, -1),
@@ -23,33 +50,60 @@ const NEIGHBOR_OFFSETS: [(isize, isize); 8] = [
     (0, 1),
     (1, 1),
 ];
+
 const SUPPORTED_SEPARATORS: [char; 3] = ['x', ',', ' '];
 
-impl Coordinate {
-    /// Creates a new coordinate.
+impl<I> Coordinate<I> {
+    /// Creates a new coordinate from components of any type.
+    ///
+    /// [`Coordinate::new`] only takes `usize` components, so that integer literals need no annotation.
+    /// Use this to construct coordinates of the compact aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Coordinate16, Coordinate32};
+    ///
+    /// assert_eq!(Coordinate16::from_components(3, 4), Coordinate16::from((3, 4)));
+    /// assert_eq!(Coordinate32::from_components(3, 4).x(), 3);
+    /// assert_eq!(Coordinate::<i64>::from_components(-3, 4).x(), -3);
+    /// ```
     #[must_use]
-    pub const fn new(x: usize, y: usize) -> Self {
+    pub const fn from_components(x: I, y: I) -> Self {
         Self { x, y }
     }
+}
 
-    /// Creates a coordinate from a grid's width and a total index.
-    #[must_use]
-    pub fn from_width_and_index(width: NonZero<usize>, index: usize) -> Self {
-        let x = index % width;
-        Self::new(x, (index - x) / width)
-    }
-
+impl<I> Coordinate<I>
+where
+    I: Copy,
+{
     /// Returns the x component
     #[must_use]
-    pub const fn x(&self) -> usize {
+    pub const fn x(&self) -> I {
         self.x
     }
 
     /// Returns the y component
     #[must_use]
-    pub const fn y(&self) -> usize {
+    pub const fn y(&self) -> I {
         self.y
     }
+}
+
+impl Coordinate {
+    /// Creates a new coordinate with `usize` components.
+    #[must_use]
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a coordinate from a grid's width and a total index.
+    #[must_use]
+    pub fn from_width_and_index(width: NonZero<usize>, index: usize) -> Self {
+        let x = index % width;
+        Self::new(x, (index - x) / width)
+    }
 
     /// Converts the coordinate into a linear index.
     #[must_use]
@@ -63,12 +117,192 @@ impl Coordinate {
             .and_then(|row| row.checked_add(self.x))
     }
 
+    /// Adds an offset to the coordinate, returning `None` if the result is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Offset};
+    ///
+    /// let coordinate = Coordinate::new(2, 3);
+    /// assert_eq!(coordinate.checked_add(Offset::new(-2, 1)), Some(Coordinate::new(0, 4)));
+    /// assert_eq!(coordinate.checked_add(Offset::new(-3, 1)), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_add(&self, offset: Offset) -> Option<Self> {
+        match (
+            self.x.checked_add_signed(offset.dx()),
+            self.y.checked_add_signed(offset.dy()),
+        ) {
+            (Some(x), Some(y)) => Some(Self::new(x, y)),
+            _ => None,
+        }
+    }
+
+    /// Subtracts an offset from the coordinate, returning `None` if the result is out of range.
+    #[must_use]
+    pub const fn checked_sub(&self, offset: Offset) -> Option<Self> {
+        match (offset.dx().checked_neg(), offset.dy().checked_neg()) {
+            (Some(dx), Some(dy)) => self.checked_add(Offset::new(dx, dy)),
+            _ => None,
+        }
+    }
+
+    /// Adds an offset to the coordinate, clamping each component to the range of `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Offset};
+    ///
+    /// let coordinate = Coordinate::new(2, 3);
+    /// assert_eq!(coordinate.saturating_add(Offset::new(-5, 1)), Coordinate::new(0, 4));
+    /// ```
+    #[must_use]
+    pub const fn saturating_add(&self, offset: Offset) -> Self {
+        Self::new(
+            self.x.saturating_add_signed(offset.dx()),
+            self.y.saturating_add_signed(offset.dy()),
+        )
+    }
+
+    /// Returns the offset that leads from this coordinate to `other`.
+    ///
+    /// Returns `None` if a component of the offset does not fit into an `isize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Offset};
+    ///
+    /// let from = Coordinate::new(5, 1);
+    /// let to = Coordinate::new(2, 3);
+    /// assert_eq!(from.offset_to(to), Some(Offset::new(-3, 2)));
+    /// assert_eq!(Coordinate::new(0, 0).offset_to(Coordinate::new(usize::MAX, 0)), None);
+    /// ```
+    #[must_use]
+    pub fn offset_to(&self, other: impl Into<Self>) -> Option<Offset> {
+        let other = other.into();
+        signed_difference(other.x, self.x)
+            .and_then(|dx| signed_difference(other.y, self.y).map(|dy| Offset::new(dx, dy)))
+    }
+
+    /// Returns the manhattan distance to `other`, saturating at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Coordinate;
+    ///
+    /// assert_eq!(Coordinate::new(1, 2).manhattan_distance(Coordinate::new(4, 6)), 7);
+    /// ```
+    #[must_use]
+    pub fn manhattan_distance(&self, other: impl Into<Self>) -> usize {
+        let other = other.into();
+        self.x
+            .abs_diff(other.x)
+            .saturating_add(self.y.abs_diff(other.y))
+    }
+
+    /// Returns the chebyshev distance to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Coordinate;
+    ///
+    /// assert_eq!(Coordinate::new(1, 2).chebyshev_distance(Coordinate::new(4, 6)), 4);
+    /// ```
+    #[must_use]
+    pub fn chebyshev_distance(&self, other: impl Into<Self>) -> usize {
+        let other = other.into();
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Returns the squared euclidean distance to `other`, saturating at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Coordinate;
+    ///
+    /// assert_eq!(Coordinate::new(1, 2).squared_euclidean_distance(Coordinate::new(4, 6)), 25);
+    /// ```
+    #[must_use]
+    pub fn squared_euclidean_distance(&self, other: impl Into<Self>) -> usize {
+        let other = other.into();
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy))
+    }
+
+    /// Returns the coordinate one step into the given direction.
+    ///
+    /// Returns `None` if the step would leave the range of `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Direction};
+    ///
+    /// assert_eq!(Coordinate::new(1, 1).step(Direction::NorthWest), Some(Coordinate::new(0, 0)));
+    /// assert_eq!(Coordinate::new(0, 1).step(Direction::West), None);
+    /// ```
+    #[must_use]
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        self + &direction.offset()
+    }
+
+    /// Returns the coordinate one step into the given direction, if it is on the given grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Direction, Grid};
+    ///
+    /// let grid = Grid::new(NonZero::new(2).unwrap(), NonZero::new(2).unwrap(), || 0);
+    ///
+    /// assert_eq!(
+    ///     Coordinate::new(0, 0).checked_step_in(&grid, Direction::SouthEast),
+    ///     Some(Coordinate::new(1, 1))
+    /// );
+    /// assert_eq!(Coordinate::new(1, 0).checked_step_in(&grid, Direction::East), None);
+    /// ```
+    #[must_use]
+    pub fn checked_step_in<T>(&self, grid: &Grid<T>, direction: Direction) -> Option<Self> {
+        self.step(direction)
+            .filter(|coordinate| grid.encompasses(*coordinate))
+    }
+
     /// Returns all potential neighboring coordinates.
     pub fn neighbors(&self) -> impl Iterator<Item = Self> + '_ {
         NEIGHBOR_OFFSETS
             .iter()
             .filter_map(move |offset| self + offset)
     }
+
+    /// Returns the potential neighboring coordinates with the given connectivity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Connectivity, Coordinate};
+    ///
+    /// let origin = Coordinate::new(0, 0);
+    ///
+    /// assert_eq!(
+    ///     origin.neighbors_with(Connectivity::Four).collect::<Vec<_>>(),
+    ///     [Coordinate::new(1, 0), Coordinate::new(0, 1)]
+    /// );
+    /// assert_eq!(origin.neighbors_with(Connectivity::Eight).count(), 3);
+    /// ```
+    pub fn neighbors_with(&self, connectivity: Connectivity) -> impl Iterator<Item = Self> + '_ {
+        connectivity
+            .directions()
+            .iter()
+            .filter_map(move |direction| self.step(*direction))
+    }
 }
 
 impl Add<&(isize, isize)> for &Coordinate {
@@ -83,19 +317,107 @@ impl Add<&(isize, isize)> for &Coordinate {
     }
 }
 
-impl Display for Coordinate {
+/// Add an offset to a coordinate.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::{Coordinate, Offset};
+///
+/// assert_eq!(Coordinate::new(1, 1) + Offset::new(1, -1), Some(Coordinate::new(2, 0)));
+/// assert_eq!(Coordinate::new(1, 1) + Offset::new(-2, 0), None);
+/// ```
+impl Add<Offset> for Coordinate {
+    type Output = Option<Self>;
+
+    fn add(self, offset: Offset) -> Self::Output {
+        self.checked_add(offset)
+    }
+}
+
+impl Add<&Offset> for &Coordinate {
+    type Output = Option<Coordinate>;
+
+    fn add(self, offset: &Offset) -> Self::Output {
+        self.checked_add(*offset)
+    }
+}
+
+/// Subtract an offset from a coordinate.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::{Coordinate, Offset};
+///
+/// assert_eq!(Coordinate::new(1, 1) - Offset::new(1, -1), Some(Coordinate::new(0, 2)));
+/// assert_eq!(Coordinate::new(1, 1) - Offset::new(2, 0), None);
+/// ```
+impl Sub<Offset> for Coordinate {
+    type Output = Option<Self>;
+
+    fn sub(self, offset: Offset) -> Self::Output {
+        self.checked_sub(offset)
+    }
+}
+
+impl Sub<&Offset> for &Coordinate {
+    type Output = Option<Coordinate>;
+
+    fn sub(self, offset: &Offset) -> Self::Output {
+        self.checked_sub(*offset)
+    }
+}
+
+/// Return the offset from `rhs` to `self`.
+///
+/// # Panics
+///
+/// Panics if a component of the offset does not fit into an `isize`.
+/// Use [`Coordinate::offset_to`] for a checked variant.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::{Coordinate, Offset};
+///
+/// assert_eq!(Coordinate::new(2, 3) - Coordinate::new(5, 1), Offset::new(-3, 2));
+/// ```
+impl Sub for Coordinate {
+    type Output = Offset;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        rhs.offset_to(self).expect("offset overflow")
+    }
+}
+
+impl Sub for &Coordinate {
+    type Output = Offset;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<I> Display for Coordinate<I>
+where
+    I: Display,
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}x{}", self.x, self.y)
     }
 }
 
-impl From<&Self> for Coordinate {
+impl<I> From<&Self> for Coordinate<I>
+where
+    I: Copy,
+{
     fn from(coordinate: &Self) -> Self {
         *coordinate
     }
 }
 
-/// Create a coordinate from a `(usize, usize)` tuple.
+/// Create a coordinate from a `(I, I)` tuple.
 ///
 /// # Examples
 ///
@@ -104,13 +426,13 @@ impl From<&Self> for Coordinate {
 ///
 /// assert_eq!(Coordinate::new(32, 1337), (32, 1337).into());
 /// ```
-impl From<(usize, usize)> for Coordinate {
-    fn from((x, y): (usize, usize)) -> Self {
-        Self::new(x, y)
+impl<I> From<(I, I)> for Coordinate<I> {
+    fn from((x, y): (I, I)) -> Self {
+        Self { x, y }
     }
 }
 
-/// Create a Coordinate from a reference to a `(usize, usize)` tuple.
+/// Create a Coordinate from a reference to a `(I, I)` tuple.
 ///
 /// # Examples
 ///
@@ -119,13 +441,16 @@ impl From<(usize, usize)> for Coordinate {
 ///
 /// assert_eq!(Coordinate::new(32, 1337), (&(32, 1337)).into());
 /// ```
-impl From<&(usize, usize)> for Coordinate {
-    fn from((x, y): &(usize, usize)) -> Self {
-        Self::new(*x, *y)
+impl<I> From<&(I, I)> for Coordinate<I>
+where
+    I: Copy,
+{
+    fn from((x, y): &(I, I)) -> Self {
+        Self { x: *x, y: *y }
     }
 }
 
-/// Create a coordinate from a `[usize; 2]`.
+/// Create a coordinate from a `[I; 2]`.
 ///
 /// # Examples
 ///
@@ -134,13 +459,13 @@ impl From<&(usize, usize)> for Coordinate {
 ///
 /// assert_eq!(Coordinate::new(32, 1337), [32, 1337].into());
 /// ```
-impl From<[usize; 2]> for Coordinate {
-    fn from([x, y]: [usize; 2]) -> Self {
-        Self::new(x, y)
+impl<I> From<[I; 2]> for Coordinate<I> {
+    fn from([x, y]: [I; 2]) -> Self {
+        Self { x, y }
     }
 }
 
-/// Create a Coordinate from a reference to a `[usize; 2]`.
+/// Create a Coordinate from a reference to a `[I; 2]`.
 ///
 /// # Examples
 ///
@@ -149,13 +474,16 @@ impl From<[usize; 2]> for Coordinate {
 ///
 /// assert_eq!(Coordinate::new(32, 1337), (&[32, 1337]).into());
 /// ```
-impl From<&[usize; 2]> for Coordinate {
-    fn from([x, y]: &[usize; 2]) -> Self {
-        Self::new(*x, *y)
+impl<I> From<&[I; 2]> for Coordinate<I>
+where
+    I: Copy,
+{
+    fn from([x, y]: &[I; 2]) -> Self {
+        Self { x: *x, y: *y }
     }
 }
 
-/// Create a `(usize, usize)` tuple tuple from a Coordinate.
+/// Create a `(I, I)` tuple from a Coordinate.
 ///
 /// # Examples
 ///
@@ -165,13 +493,13 @@ impl From<&[usize; 2]> for Coordinate {
 /// let (x, y) = Coordinate::new(32, 1337).into();
 /// assert_eq!((32, 1337), (x, y));
 /// ```
-impl From<Coordinate> for (usize, usize) {
-    fn from(coordinate: Coordinate) -> Self {
+impl<I> From<Coordinate<I>> for (I, I) {
+    fn from(coordinate: Coordinate<I>) -> Self {
         (coordinate.x, coordinate.y)
     }
 }
 
-/// Create a `(usize, usize)` tuple from a Coordinate reference.
+/// Create a `(I, I)` tuple from a Coordinate reference.
 ///
 /// # Examples
 ///
@@ -181,13 +509,16 @@ impl From<Coordinate> for (usize, usize) {
 /// let (x, y) = (&Coordinate::new(32, 1337)).into();
 /// assert_eq!((32, 1337), (x, y));
 /// ```
-impl From<&Coordinate> for (usize, usize) {
-    fn from(coordinate: &Coordinate) -> Self {
+impl<I> From<&Coordinate<I>> for (I, I)
+where
+    I: Copy,
+{
+    fn from(coordinate: &Coordinate<I>) -> Self {
         (coordinate.x, coordinate.y)
     }
 }
 
-/// Create a `[usize; 2]` array tuple from a Coordinate.
+/// Create a `[I; 2]` array from a Coordinate.
 ///
 /// # Examples
 ///
@@ -197,13 +528,13 @@ impl From<&Coordinate> for (usize, usize) {
 /// let [x, y] = Coordinate::new(32, 1337).into();
 /// assert_eq!((32, 1337), (x, y));
 /// ```
-impl From<Coordinate> for [usize; 2] {
-    fn from(coordinate: Coordinate) -> Self {
+impl<I> From<Coordinate<I>> for [I; 2] {
+    fn from(coordinate: Coordinate<I>) -> Self {
         [coordinate.x, coordinate.y]
     }
 }
 
-/// Create a `[usize; 2]` array from a Coordinate reference.
+/// Create a `[I; 2]` array from a Coordinate reference.
 ///
 /// # Examples
 ///
@@ -213,20 +544,78 @@ impl From<Coordinate> for [usize; 2] {
 /// let [x, y] = (&Coordinate::new(32, 1337)).into();
 /// assert_eq!((32, 1337), (x, y));
 /// ```
-impl From<&Coordinate> for [usize; 2] {
-    fn from(coordinate: &Coordinate) -> Self {
+impl<I> From<&Coordinate<I>> for [I; 2]
+where
+    I: Copy,
+{
+    fn from(coordinate: &Coordinate<I>) -> Self {
         [coordinate.x, coordinate.y]
     }
 }
 
+/// Implements lossless conversions between coordinates of different component types.
+macro_rules! impl_from_coordinate {
+    ($($from:ty => $($to:ty),+;)+) => {
+        $($(
+            impl From<Coordinate<$from>> for Coordinate<$to> {
+                fn from(coordinate: Coordinate<$from>) -> Self {
+                    Self {
+                        x: coordinate.x.into(),
+                        y: coordinate.y.into(),
+                    }
+                }
+            }
+        )+)+
+    };
+}
+
+/// Implements fallible conversions between coordinates of different component types.
+macro_rules! impl_try_from_coordinate {
+    ($($from:ty => $($to:ty),+;)+) => {
+        $($(
+            impl TryFrom<Coordinate<$from>> for Coordinate<$to> {
+                type Error = TryFromIntError;
+
+                fn try_from(coordinate: Coordinate<$from>) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        x: coordinate.x.try_into()?,
+                        y: coordinate.y.try_into()?,
+                    })
+                }
+            }
+        )+)+
+    };
+}
+
+impl_from_coordinate! {
+    u8 => u16, u32, u64, usize, i16, i32, i64, isize;
+    u16 => u32, u64, usize, i32, i64;
+    u32 => u64, i64;
+    i16 => i32, i64, isize;
+    i32 => i64;
+}
+
+impl_try_from_coordinate! {
+    u16 => u8, i16, isize;
+    u32 => u8, u16, usize, i16, i32, isize;
+    u64 => u8, u16, u32, usize, i16, i32, i64, isize;
+    usize => u8, u16, u32, u64, i16, i32, i64, isize;
+    i16 => u8, u16, u32, u64, usize;
+    i32 => u8, u16, u32, u64, usize, i16, isize;
+    i64 => u8, u16, u32, u64, usize, i16, i32, isize;
+    isize => u8, u16, u32, u64, usize, i16, i32, i64;
+}
+
 /// Create a Coordinate from a `&str`.
 ///
+/// The two numbers may be separated by `x`, `,` or a space and may be enclosed in parentheses.
+///
 /// # Examples
 ///
 /// ```
 /// use core::num::IntErrorKind;
 /// use core::str::FromStr;
-/// use grid2d::{Coordinate, CoordinateParseError};
+/// use grid2d::{Coordinate, CoordinateParseError, Notation};
 ///
 /// assert!(match Coordinate::from_str("-1 1").unwrap_err() {
 ///     CoordinateParseError::InvalidXValue(e) => e.kind() == &IntErrorKind::InvalidDigit,
@@ -256,11 +645,40 @@ impl From<&Coordinate> for [usize; 2] {
 /// });
 /// assert_eq!(Coordinate::from_str("42x1337").ok(), Some(Coordinate::new(42, 1337)));
 /// assert_eq!(Coordinate::from_str("0, 0").ok(), Some(Coordinate::new(0, 0)));
+/// assert_eq!(Coordinate::from_str("(3, 4)").ok(), Some(Coordinate::new(3, 4)));
+/// assert_eq!(Coordinate::from_str("( 3 x 4 )").ok(), Some(Coordinate::new(3, 4)));
+/// assert_eq!(
+///     Coordinate::from_str("(3, 4"),
+///     Err(CoordinateParseError::UnexpectedEnd { notation: Notation::Parenthesized })
+/// );
+/// assert_eq!(
+///     Coordinate::from_str("(3, 4]"),
+///     Err(CoordinateParseError::UnexpectedCharacter {
+///         notation: Notation::Parenthesized,
+///         position: 5,
+///         character: ']',
+///     })
+/// );
+/// assert_eq!(
+///     Coordinate::from_str("(3,4)x"),
+///     Err(CoordinateParseError::UnexpectedCharacter {
+///         notation: Notation::Parenthesized,
+///         position: 5,
+///         character: 'x',
+///     })
+/// );
 /// ```
 impl FromStr for Coordinate {
     type Err = CoordinateParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some(inner) = string.strip_prefix('(') {
+            return inner.strip_suffix(')').map_or_else(
+                || Err(unclosed_parenthesis(string)),
+                |inner| Self::from_str(inner.trim()),
+            );
+        }
+
         match SUPPORTED_SEPARATORS
             .into_iter()
             .find_map(|char| string.split_once(char))
@@ -294,3 +712,47 @@ impl TryFrom<(&str, &str)> for Coordinate {
             })
     }
 }
+
+/// Returns the error for a parenthesized coordinate that does not end with the closing parenthesis.
+///
+/// If the closing parenthesis is followed by further characters, the first of them is reported.
+fn unclosed_parenthesis(string: &str) -> CoordinateParseError {
+    if let Some((position, character)) = string
+        .chars()
+        .enumerate()
+        .skip_while(|(_, character)| *character != ')')
+        .nth(1)
+    {
+        return CoordinateParseError::UnexpectedCharacter {
+            notation: Notation::Parenthesized,
+            position,
+            character,
+        };
+    }
+
+    match string.chars().last() {
+        Some(character)
+            if !(character.is_ascii_digit()
+                || character.is_whitespace()
+                || SUPPORTED_SEPARATORS.contains(&character)) =>
+        {
+            CoordinateParseError::UnexpectedCharacter {
+                notation: Notation::Parenthesized,
+                position: string.chars().count() - 1,
+                character,
+            }
+        }
+        _ => CoordinateParseError::UnexpectedEnd {
+            notation: Notation::Parenthesized,
+        },
+    }
+}
+
+/// Returns `to - from` as an `isize`, if it fits.
+fn signed_difference(to: usize, from: usize) -> Option<isize> {
+    if to >= from {
+        isize::try_from(to - from).ok()
+    } else {
+        0isize.checked_sub_unsigned(from - to)
+    }
+}
@@ -0,0 +1,327 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+use core::num::NonZero;
+
+use super::Coordinate;
+use crate::CoordinateParseError;
+
+/// Textual notations of coordinates.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Notation {
+    /// Two separated numbers in parentheses, such as `(3, 4)`.
+    Parenthesized,
+    /// Column letters followed by a one-based row number, such as `B3` or `AA10`.
+    Spreadsheet,
+    /// A file letter followed by a rank counted from the bottom of the board, such as `e4`.
+    Chess,
+}
+
+impl Display for Notation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parenthesized => write!(f, "parenthesized"),
+            Self::Spreadsheet => write!(f, "spreadsheet"),
+            Self::Chess => write!(f, "chess"),
+        }
+    }
+}
+
+impl Coordinate {
+    /// Parses a coordinate from spreadsheet notation, such as `B3` or `AA10`.
+    ///
+    /// Column letters are case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CoordinateParseError`] if the string is not in spreadsheet notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, CoordinateParseError, Notation};
+    ///
+    /// assert_eq!(Coordinate::from_a1("A1"), Ok(Coordinate::new(0, 0)));
+    /// assert_eq!(Coordinate::from_a1("B3"), Ok(Coordinate::new(1, 2)));
+    /// assert_eq!(Coordinate::from_a1("aa10"), Ok(Coordinate::new(26, 9)));
+    /// assert_eq!(
+    ///     Coordinate::from_a1("B3x"),
+    ///     Err(CoordinateParseError::UnexpectedCharacter {
+    ///         notation: Notation::Spreadsheet,
+    ///         position: 2,
+    ///         character: 'x',
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::from_a1("AB"),
+    ///     Err(CoordinateParseError::UnexpectedEnd { notation: Notation::Spreadsheet })
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::from_a1("A0"),
+    ///     Err(CoordinateParseError::OutOfRange { notation: Notation::Spreadsheet, position: 1 })
+    /// );
+    /// ```
+    pub fn from_a1(string: &str) -> Result<Self, CoordinateParseError> {
+        parse_letters_and_number(string, Notation::Spreadsheet, 0)
+            .map(|(column, row)| Self::new(column, row.get() - 1))
+    }
+
+    /// Parses a range of coordinates from spreadsheet notation, such as `A1:C5`.
+    ///
+    /// Returns the two corners of the range in the order they appear in the string.
+    /// Error positions refer to the whole string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CoordinateParseError`] if the string is not a range in spreadsheet notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, CoordinateParseError, Notation};
+    ///
+    /// assert_eq!(
+    ///     Coordinate::range_from_a1("A1:C5"),
+    ///     Ok((Coordinate::new(0, 0), Coordinate::new(2, 4)))
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::range_from_a1("A1:5"),
+    ///     Err(CoordinateParseError::UnexpectedCharacter {
+    ///         notation: Notation::Spreadsheet,
+    ///         position: 3,
+    ///         character: '5',
+    ///     })
+    /// );
+    /// ```
+    pub fn range_from_a1(string: &str) -> Result<(Self, Self), CoordinateParseError> {
+        let Some((start, end)) = string.split_once(':') else {
+            return Self::from_a1(string).and(Err(CoordinateParseError::UnexpectedEnd {
+                notation: Notation::Spreadsheet,
+            }));
+        };
+
+        let start = Self::from_a1(start)?;
+        let offset = string
+            .chars()
+            .position(|char| char == ':')
+            .unwrap_or_default()
+            + 1;
+        parse_letters_and_number(end, Notation::Spreadsheet, offset)
+            .map(|(column, row)| (start, Self::new(column, row.get() - 1)))
+    }
+
+    /// Formats the range from this coordinate to `end` in spreadsheet notation, such as `A1:C5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Coordinate;
+    ///
+    /// let (start, end) = Coordinate::range_from_a1("A1:C5").unwrap();
+    /// assert_eq!(start.to_a1_range(end), "A1:C5");
+    /// assert_eq!(Coordinate::new(26, 9).to_a1_range(Coordinate::new(1, 2)), "AA10:B3");
+    /// ```
+    #[must_use]
+    pub fn to_a1_range(&self, end: Self) -> String {
+        format!("{}:{}", self.to_a1(), end.to_a1())
+    }
+
+    /// Formats the coordinate in spreadsheet notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Coordinate;
+    ///
+    /// assert_eq!(Coordinate::new(0, 0).to_a1(), "A1");
+    /// assert_eq!(Coordinate::new(1, 2).to_a1(), "B3");
+    /// assert_eq!(Coordinate::new(26, 9).to_a1(), "AA10");
+    /// assert_eq!(Coordinate::new(701, 0).to_a1(), "ZZ1");
+    /// ```
+    #[must_use]
+    pub fn to_a1(&self) -> String {
+        format!("{}{}", column_letters(self.x, b'A'), self.y as u128 + 1)
+    }
+
+    /// Formats the coordinate in parenthesized notation, such as `(3, 4)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Coordinate;
+    ///
+    /// let coordinate = Coordinate::new(3, 4);
+    /// assert_eq!(coordinate.to_parenthesized(), "(3, 4)");
+    /// assert_eq!(coordinate.to_parenthesized().parse(), Ok(coordinate));
+    /// ```
+    #[must_use]
+    pub fn to_parenthesized(&self) -> String {
+        format!("({}, {})", self.x, self.y)
+    }
+
+    /// Parses a coordinate from chess notation, such as `e4`, on a board of the given height.
+    ///
+    /// Ranks are counted from the bottom of the board, so `a1` is the lower left cell.
+    /// File letters are case-insensitive and may exceed `z` for wide boards, like spreadsheet columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CoordinateParseError`] if the string is not in chess notation
+    /// or the rank exceeds the height of the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, CoordinateParseError, Notation};
+    ///
+    /// let height = NonZero::new(8).unwrap();
+    /// assert_eq!(Coordinate::from_chess("a8", height), Ok(Coordinate::new(0, 0)));
+    /// assert_eq!(Coordinate::from_chess("e4", height), Ok(Coordinate::new(4, 4)));
+    /// assert_eq!(Coordinate::from_chess("h1", height), Ok(Coordinate::new(7, 7)));
+    /// assert_eq!(
+    ///     Coordinate::from_chess("e9", height),
+    ///     Err(CoordinateParseError::OutOfRange { notation: Notation::Chess, position: 1 })
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::from_chess("4e", height),
+    ///     Err(CoordinateParseError::UnexpectedCharacter {
+    ///         notation: Notation::Chess,
+    ///         position: 0,
+    ///         character: '4',
+    ///     })
+    /// );
+    /// ```
+    pub fn from_chess(string: &str, height: NonZero<usize>) -> Result<Self, CoordinateParseError> {
+        let (file, rank) = parse_letters_and_number(string, Notation::Chess, 0)?;
+
+        if rank > height {
+            return Err(CoordinateParseError::OutOfRange {
+                notation: Notation::Chess,
+                position: string
+                    .chars()
+                    .position(|char| char.is_ascii_digit())
+                    .unwrap_or_default(),
+            });
+        }
+
+        Ok(Self::new(file, height.get() - rank.get()))
+    }
+
+    /// Formats the coordinate in chess notation on a board of the given height.
+    ///
+    /// Returns `None` if the coordinate is below the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Coordinate;
+    ///
+    /// let height = NonZero::new(8).unwrap();
+    /// assert_eq!(Coordinate::new(4, 4).to_chess(height).as_deref(), Some("e4"));
+    /// assert_eq!(Coordinate::new(0, 7).to_chess(height).as_deref(), Some("a1"));
+    /// assert_eq!(Coordinate::new(0, 8).to_chess(height), None);
+    /// ```
+    #[must_use]
+    pub fn to_chess(&self, height: NonZero<usize>) -> Option<String> {
+        if self.y >= height.get() {
+            return None;
+        }
+
+        Some(format!(
+            "{}{}",
+            column_letters(self.x, b'a'),
+            height.get() - self.y
+        ))
+    }
+}
+
+/// Parses bijective base-26 letters followed by a positive decimal number.
+///
+/// Returns the zero-based column and the one-based number.
+/// Error positions are offset by `offset` characters.
+fn parse_letters_and_number(
+    string: &str,
+    notation: Notation,
+    offset: usize,
+) -> Result<(usize, NonZero<usize>), CoordinateParseError> {
+    let mut chars = string.chars().enumerate().peekable();
+    let mut column: usize = 0;
+    let mut letters = 0;
+
+    while let Some((_, char)) = chars.next_if(|(_, char)| char.is_ascii_alphabetic()) {
+        let digit = usize::from(char.to_ascii_uppercase() as u8 - b'A') + 1;
+        column = column
+            .checked_mul(26)
+            .and_then(|column| column.checked_add(digit))
+            .ok_or(CoordinateParseError::OutOfRange {
+                notation,
+                position: offset,
+            })?;
+        letters += 1;
+    }
+
+    if letters == 0 {
+        return Err(unexpected(chars.next(), notation, offset));
+    }
+
+    let mut number: usize = 0;
+    let mut digits = 0;
+
+    while let Some((_, char)) = chars.next_if(|(_, char)| char.is_ascii_digit()) {
+        let digit = usize::from(char as u8 - b'0');
+        number = number
+            .checked_mul(10)
+            .and_then(|number| number.checked_add(digit))
+            .ok_or(CoordinateParseError::OutOfRange {
+                notation,
+                position: offset + letters,
+            })?;
+        digits += 1;
+    }
+
+    if digits == 0 || chars.peek().is_some() {
+        return Err(unexpected(chars.next(), notation, offset));
+    }
+
+    NonZero::new(number)
+        .map(|number| (column - 1, number))
+        .ok_or(CoordinateParseError::OutOfRange {
+            notation,
+            position: offset + letters,
+        })
+}
+
+/// Returns the error for an unexpected character or the unexpected end of the string.
+const fn unexpected(
+    next: Option<(usize, char)>,
+    notation: Notation,
+    offset: usize,
+) -> CoordinateParseError {
+    match next {
+        Some((position, character)) => CoordinateParseError::UnexpectedCharacter {
+            notation,
+            position: offset + position,
+            character,
+        },
+        None => CoordinateParseError::UnexpectedEnd { notation },
+    }
+}
+
+/// Returns the bijective base-26 letters of the zero-based column.
+fn column_letters(column: usize, first: u8) -> String {
+    let mut letters = String::new();
+    let mut remainder = column;
+
+    loop {
+        #[expect(clippy::cast_possible_truncation)]
+        letters.insert(0, char::from(first + (remainder % 26) as u8));
+
+        if remainder < 26 {
+            return letters;
+        }
+
+        remainder = remainder / 26 - 1;
+    }
+}
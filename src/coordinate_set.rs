@@ -0,0 +1,347 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::num::NonZero;
+
+use crate::{Coordinate, Grid};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of coordinates on a grid of fixed dimensions.
+///
+/// The set is backed by a bitmap with one bit per cell, which makes it considerably faster
+/// and smaller than a hash set for dense collections, such as the visited cells of a search.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CoordinateSet {
+    width: NonZero<usize>,
+    height: NonZero<usize>,
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl CoordinateSet {
+    /// Returns a new empty set for a grid of the given width and height.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the grid size is too large to fit into a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::CoordinateSet;
+    ///
+    /// let set = CoordinateSet::new(NonZero::new(3).unwrap(), NonZero::new(2).unwrap());
+    ///
+    /// assert!(set.is_empty());
+    /// assert_eq!(set.width().get(), 3);
+    /// assert_eq!(set.height().get(), 2);
+    /// ```
+    #[must_use]
+    pub fn new(width: NonZero<usize>, height: NonZero<usize>) -> Self {
+        Self::try_new(width, height).expect("grid too large")
+    }
+
+    /// Returns a new empty set for a grid of the given width and height.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `None` if the grid size is too large to fit into a `usize`.
+    #[must_use]
+    pub fn try_new(width: NonZero<usize>, height: NonZero<usize>) -> Option<Self> {
+        let size = width.checked_mul(height)?.get();
+        Some(Self {
+            width,
+            height,
+            words: vec![0; size.div_ceil(BITS)],
+            len: 0,
+        })
+    }
+
+    /// Returns a new empty set matching the dimensions of the given grid.
+    #[must_use]
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid.width(), grid.height())
+    }
+
+    /// Returns the width of the grid the set is bounded by.
+    #[must_use]
+    pub const fn width(&self) -> NonZero<usize> {
+        self.width
+    }
+
+    /// Returns the height of the grid the set is bounded by.
+    #[must_use]
+    pub const fn height(&self) -> NonZero<usize> {
+        self.height
+    }
+
+    /// Returns the amount of coordinates in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true, if the set contains no coordinates, else false.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds a coordinate to the set.
+    ///
+    /// Returns `true` if the coordinate was not in the set before.
+    /// Coordinates outside the grid are never added and yield `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Coordinate16, CoordinateSet};
+    ///
+    /// let mut set = CoordinateSet::new(NonZero::new(3).unwrap(), NonZero::new(2).unwrap());
+    ///
+    /// assert!(set.insert(Coordinate::new(2, 1)));
+    /// assert!(!set.insert(Coordinate::new(2, 1)));
+    /// assert!(!set.insert(Coordinate::new(3, 0)));
+    /// assert!(set.insert(Coordinate16::from((0, 1))));
+    /// assert!(!set.insert(Coordinate::<i32>::from((-1, 0))));
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn insert(&mut self, coordinate: impl TryInto<Coordinate>) -> bool {
+        let Some((word, mask)) = self.position(coordinate) else {
+            return false;
+        };
+
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        self.len += usize::from(inserted);
+        inserted
+    }
+
+    /// Removes a coordinate from the set.
+    ///
+    /// Returns `true` if the coordinate was in the set.
+    pub fn remove(&mut self, coordinate: impl TryInto<Coordinate>) -> bool {
+        let Some((word, mask)) = self.position(coordinate) else {
+            return false;
+        };
+
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        self.len -= usize::from(removed);
+        removed
+    }
+
+    /// Determines whether the set contains the given coordinate.
+    pub fn contains(&self, coordinate: impl TryInto<Coordinate>) -> bool {
+        self.position(coordinate)
+            .is_some_and(|(word, mask)| self.words[word] & mask != 0)
+    }
+
+    /// Removes all coordinates from the set.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+
+    /// Adds all coordinates of `other` to this set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the sets are bounded by grids of different dimensions.
+    pub fn union_with(&mut self, other: &Self) {
+        self.combine(other, |own, others| own | others);
+    }
+
+    /// Removes all coordinates from this set that are not in `other`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the sets are bounded by grids of different dimensions.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.combine(other, |own, others| own & others);
+    }
+
+    /// Removes all coordinates of `other` from this set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the sets are bounded by grids of different dimensions.
+    pub fn difference_with(&mut self, other: &Self) {
+        self.combine(other, |own, others| own & !others);
+    }
+
+    /// Returns a set of the coordinates that are in either set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the sets are bounded by grids of different dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, CoordinateSet};
+    ///
+    /// let width = NonZero::new(3).unwrap();
+    /// let height = NonZero::new(3).unwrap();
+    /// let mut a = CoordinateSet::new(width, height);
+    /// a.extend([Coordinate::new(0, 0), Coordinate::new(1, 1)]);
+    /// let mut b = CoordinateSet::new(width, height);
+    /// b.extend([Coordinate::new(1, 1), Coordinate::new(2, 2)]);
+    ///
+    /// assert_eq!(a.union(&b).len(), 3);
+    /// assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [Coordinate::new(1, 1)]);
+    /// assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), [Coordinate::new(0, 0)]);
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.union_with(other);
+        union
+    }
+
+    /// Returns a set of the coordinates that are in both sets.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the sets are bounded by grids of different dimensions.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = self.clone();
+        intersection.intersect_with(other);
+        intersection
+    }
+
+    /// Returns a set of the coordinates that are in this set, but not in `other`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the sets are bounded by grids of different dimensions.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        difference.difference_with(other);
+        difference
+    }
+
+    /// Yields the coordinates in the set in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, CoordinateSet};
+    ///
+    /// let mut set = CoordinateSet::new(NonZero::new(100).unwrap(), NonZero::new(2).unwrap());
+    /// set.extend([Coordinate::new(5, 1), Coordinate::new(99, 0), Coordinate::new(0, 0)]);
+    ///
+    /// assert_eq!(
+    ///     set.iter().collect::<Vec<_>>(),
+    ///     [Coordinate::new(0, 0), Coordinate::new(99, 0), Coordinate::new(5, 1)]
+    /// );
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        let width = self.width;
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, word)| {
+                Bits(*word)
+                    .map(move |bit| Coordinate::from_width_and_index(width, index * BITS + bit))
+            })
+    }
+
+    fn position(&self, coordinate: impl TryInto<Coordinate>) -> Option<(usize, u64)> {
+        let coordinate = coordinate.try_into().ok()?;
+
+        if coordinate.y() >= self.height.get() {
+            return None;
+        }
+
+        coordinate
+            .as_index(self.width)
+            .map(|index| (index / BITS, 1 << (index % BITS)))
+    }
+
+    fn combine(&mut self, other: &Self, operation: impl Fn(u64, u64) -> u64) {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "dimensions of coordinate sets do not match"
+        );
+
+        self.len = 0;
+
+        for (own, others) in self.words.iter_mut().zip(&other.words) {
+            *own = operation(*own, *others);
+            self.len += own.count_ones() as usize;
+        }
+    }
+}
+
+impl<C> Extend<C> for CoordinateSet
+where
+    C: TryInto<Coordinate>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = C>,
+    {
+        for coordinate in iter {
+            self.insert(coordinate);
+        }
+    }
+}
+
+/// Yields the indices of the set bits of a word in ascending order.
+struct Bits(u64);
+
+impl Iterator for Bits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let bit = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl FusedIterator for Bits {}
+
+/// Create a mask from a set, in which the cells of the contained coordinates are `true`.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZero;
+/// use grid2d::{Coordinate, CoordinateSet, Grid};
+///
+/// let mut set = CoordinateSet::new(NonZero::new(2).unwrap(), NonZero::new(2).unwrap());
+/// set.insert(Coordinate::new(1, 0));
+///
+/// assert_eq!(Grid::from(&set).as_ref(), [false, true, false, false]);
+/// ```
+impl From<&CoordinateSet> for Grid<bool> {
+    fn from(set: &CoordinateSet) -> Self {
+        let cells = (0..set.width.get() * set.height.get())
+            .map(|index| set.contains(Coordinate::from_width_and_index(set.width, index)))
+            .collect();
+
+        #[expect(unsafe_code)]
+        // SAFETY: There is exactly one cell for each coordinate of the set's grid.
+        unsafe {
+            Self::new_unchecked(set.width, cells)
+        }
+    }
+}
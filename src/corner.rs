@@ -0,0 +1,59 @@
+use core::fmt::{self, Display, Formatter};
+
+/// One of the four corners of a cell on a grid.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Corner {
+    /// The upper left corner of a cell.
+    NorthWest,
+    /// The upper right corner of a cell.
+    NorthEast,
+    /// The lower right corner of a cell.
+    SouthEast,
+    /// The lower left corner of a cell.
+    SouthWest,
+}
+
+impl Corner {
+    /// All four corners in clockwise order, starting with [`Corner::NorthWest`].
+    pub const ALL: [Self; 4] = [
+        Self::NorthWest,
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::SouthWest,
+    ];
+
+    /// Returns the diagonally opposite corner.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::NorthWest => Self::SouthEast,
+            Self::NorthEast => Self::SouthWest,
+            Self::SouthEast => Self::NorthWest,
+            Self::SouthWest => Self::NorthEast,
+        }
+    }
+
+    /// Returns the `(dx, dy)` offset of the corner's vertex relative to the cell's coordinate.
+    ///
+    /// The vertex of the upper left corner of a cell shares the cell's coordinate.
+    #[must_use]
+    pub const fn offset(self) -> (usize, usize) {
+        match self {
+            Self::NorthWest => (0, 0),
+            Self::NorthEast => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::SouthWest => (0, 1),
+        }
+    }
+}
+
+impl Display for Corner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NorthWest => write!(f, "north-west"),
+            Self::NorthEast => write!(f, "north-east"),
+            Self::SouthEast => write!(f, "south-east"),
+            Self::SouthWest => write!(f, "south-west"),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use core::ops::Add;
+
+/// A cost of moving across a grid, as used by the pathfinding algorithms.
+///
+/// Costs must not be negative.
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    /// The cost of not moving at all.
+    const ZERO: Self;
+
+    /// Returns the cost of taking the given amount of steps with this cost each.
+    ///
+    /// The result saturates at the maximum value of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Cost;
+    ///
+    /// assert_eq!(3u32.times(4), 12);
+    /// assert_eq!(100u8.times(3), u8::MAX);
+    /// ```
+    #[must_use]
+    fn times(self, steps: usize) -> Self;
+}
+
+macro_rules! impl_cost {
+    ($($typ:ty),+) => {
+        $(
+            impl Cost for $typ {
+                const ZERO: Self = 0;
+
+                fn times(self, steps: usize) -> Self {
+                    Self::try_from(steps).map_or(Self::MAX, |steps| self.saturating_mul(steps))
+                }
+            }
+        )+
+    };
+}
+
+impl_cost!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
@@ -0,0 +1,196 @@
+use alloc::collections::BinaryHeap;
+use core::cmp::Reverse;
+
+use crate::{Connectivity, Coordinate, Cost, Direction, Grid};
+
+impl<T> Grid<T> {
+    /// Returns the cost of the cheapest path from each cell to the nearest goal.
+    ///
+    /// The cost function returns the cost of entering a cell, or `None` if it is impassable.
+    /// Goals are always reachable at zero cost, goals outside the grid are ignored.
+    /// Cells that cannot reach any goal, as well as impassable cells other than goals, have no cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Coordinate, Grid};
+    ///
+    /// let grid = Grid::try_from(("..#.....".chars(), NonZero::new(4).unwrap())).unwrap();
+    /// let goals = [Coordinate::new(0, 0), Coordinate::new(3, 0)];
+    /// let map = grid.dijkstra_map(goals, Connectivity::Four, |cell| (*cell != '#').then_some(1));
+    ///
+    /// assert_eq!(
+    ///     map.iter().copied().collect::<Vec<_>>(),
+    ///     [Some(0), Some(1), None, Some(0), Some(1), Some(2), Some(2), Some(1)]
+    /// );
+    ///
+    /// let costs = Grid::try_from(([1, 5, 2], NonZero::new(3).unwrap())).unwrap();
+    /// let map = costs.dijkstra_map([Coordinate::new(0, 0)], Connectivity::Four, |cost| Some(*cost));
+    /// let path = costs.dijkstra((2, 0), (0, 0), Connectivity::Four, |cost, _, _| Some(*cost));
+    ///
+    /// assert_eq!(map.iter().copied().collect::<Vec<_>>(), [Some(0), Some(1), Some(6)]);
+    /// assert_eq!(path.unwrap().cost(), 6);
+    /// ```
+    pub fn dijkstra_map<C>(
+        &self,
+        goals: impl IntoIterator<Item = C>,
+        connectivity: Connectivity,
+        cost: impl Fn(&T) -> Option<u32>,
+    ) -> Grid<Option<u32>>
+    where
+        C: TryInto<Coordinate>,
+    {
+        let mut initial = Grid::new(self.width(), self.height(), || None);
+
+        for goal in goals {
+            if let Some(value) = initial.get_mut(goal) {
+                *value = Some(0);
+            }
+        }
+
+        self.rescan(initial, connectivity, cost)
+    }
+
+    /// Returns a map that leads away from the goals of the given Dijkstra map.
+    ///
+    /// The distances are scaled by `-factor` and rounded towards zero,
+    /// after which the map is rescanned, so that following it towards lower values leads
+    /// to cells far away from the goals, rather than into dead ends near them.
+    /// Factors slightly above one, such as `1.2`, are common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Coordinate, Direction, Grid};
+    ///
+    /// let grid = Grid::try_from((".....".chars(), NonZero::new(5).unwrap())).unwrap();
+    /// let cost = |_: &char| Some(1);
+    /// let map = grid.dijkstra_map([Coordinate::new(1, 0)], Connectivity::Four, cost);
+    /// let flee = grid.flee_map(&map, 1.2, Connectivity::Four, cost);
+    ///
+    /// assert_eq!(
+    ///     flee.iter().copied().collect::<Vec<_>>(),
+    ///     [Some(-1), Some(0), Some(-1), Some(-2), Some(-3)]
+    /// );
+    /// assert_eq!(
+    ///     flee.flow_field(Connectivity::Four)[Coordinate::new(1, 0)],
+    ///     Some(Direction::East)
+    /// );
+    /// ```
+    pub fn flee_map(
+        &self,
+        distances: &Grid<Option<u32>>,
+        factor: f64,
+        connectivity: Connectivity,
+        cost: impl Fn(&T) -> Option<u32>,
+    ) -> Grid<Option<i64>> {
+        let initial = distances.map(|distance| distance.map(|distance| scale(distance, -factor)));
+
+        self.rescan(initial, connectivity, cost)
+    }
+
+    /// Lowers the value of each passable cell wherever stepping into a neighbor
+    /// is cheaper, which costs the value of the neighbor plus the cost of entering it.
+    fn rescan<C>(
+        &self,
+        mut map: Grid<Option<C>>,
+        connectivity: Connectivity,
+        cost: impl Fn(&T) -> Option<u32>,
+    ) -> Grid<Option<C>>
+    where
+        C: Cost + From<u32>,
+    {
+        let mut heap: BinaryHeap<_> = map
+            .enumerate()
+            .filter_map(|(coordinate, value)| {
+                value.map(|value| Reverse((value, coordinate.y(), coordinate.x())))
+            })
+            .collect();
+
+        while let Some(Reverse((value, y, x))) = heap.pop() {
+            let coordinate = Coordinate::new(x, y);
+
+            if map
+                .get(coordinate)
+                .copied()
+                .flatten()
+                .is_some_and(|best| value > best)
+            {
+                continue;
+            }
+
+            let Some(step) = self.get(coordinate).and_then(&cost) else {
+                continue;
+            };
+            let total = value + C::from(step);
+
+            for neighbor in coordinate.neighbors_with(connectivity) {
+                if self.get(neighbor).and_then(&cost).is_none() {
+                    continue;
+                }
+
+                if let Some(target) = map.get_mut(neighbor)
+                    && target.is_none_or(|best| total < best)
+                {
+                    *target = Some(total);
+                    heap.push(Reverse((total, neighbor.y(), neighbor.x())));
+                }
+            }
+        }
+
+        map
+    }
+}
+
+impl<C> Grid<Option<C>>
+where
+    C: Copy + Ord,
+{
+    /// Returns the direction towards the neighbor with the lowest value for each cell.
+    ///
+    /// Cells without a value or without a neighbor of lower value, such as goals, have no direction.
+    /// Ties are resolved in the order of [`Connectivity::directions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Coordinate, Direction, Grid};
+    ///
+    /// let grid = Grid::try_from(("...#.".chars(), NonZero::new(5).unwrap())).unwrap();
+    /// let map = grid.dijkstra_map([Coordinate::new(0, 0)], Connectivity::Four, |cell| {
+    ///     (*cell != '#').then_some(1)
+    /// });
+    ///
+    /// assert_eq!(
+    ///     map.flow_field(Connectivity::Four).iter().copied().collect::<Vec<_>>(),
+    ///     [None, Some(Direction::West), Some(Direction::West), None, None]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn flow_field(&self, connectivity: Connectivity) -> Grid<Option<Direction>> {
+        self.map_with_coordinate(|coordinate, value| {
+            let value = (*value)?;
+            connectivity
+                .directions()
+                .iter()
+                .filter_map(|direction| {
+                    coordinate
+                        .step(*direction)
+                        .and_then(|neighbor| self.get(neighbor).copied().flatten())
+                        .filter(|neighbor| *neighbor < value)
+                        .map(|neighbor| (neighbor, *direction))
+                })
+                .min_by_key(|(neighbor, _)| *neighbor)
+                .map(|(_, direction)| direction)
+        })
+    }
+}
+
+/// Scales a distance by a factor, rounding towards zero.
+#[expect(clippy::cast_possible_truncation)]
+fn scale(distance: u32, factor: f64) -> i64 {
+    (f64::from(distance) * factor) as i64
+}
@@ -0,0 +1,297 @@
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::{DirectionParseError, Side};
+
+/// A compass direction on a grid, including the diagonals.
+///
+/// The y axis points downwards, so [`Direction::North`] points towards the row above.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    /// Upwards.
+    North,
+    /// Upwards and to the right.
+    NorthEast,
+    /// To the right.
+    East,
+    /// Downwards and to the right.
+    SouthEast,
+    /// Downwards.
+    South,
+    /// Downwards and to the left.
+    SouthWest,
+    /// To the left.
+    West,
+    /// Upwards and to the left.
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions in clockwise order, starting with [`Direction::North`].
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// The four cardinal directions in clockwise order, starting with [`Direction::North`].
+    pub const CARDINAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// The four diagonal directions in clockwise order, starting with [`Direction::NorthEast`].
+    pub const DIAGONAL: [Self; 4] = [
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::NorthWest,
+    ];
+
+    /// Returns the `(dx, dy)` offset of a single step into this direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Direction};
+    ///
+    /// assert_eq!(Direction::North.offset(), (0, -1));
+    /// assert_eq!(Direction::SouthEast.offset(), (1, 1));
+    /// assert_eq!(
+    ///     &Coordinate::new(1, 1) + &Direction::West.offset(),
+    ///     Some(Coordinate::new(0, 1))
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Direction;
+    ///
+    /// assert_eq!(Direction::North.opposite(), Direction::South);
+    /// assert_eq!(Direction::NorthEast.opposite(), Direction::SouthWest);
+    /// ```
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Returns the direction after turning left by 90°.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Direction;
+    ///
+    /// assert_eq!(Direction::North.turn_left(), Direction::West);
+    /// assert_eq!(Direction::SouthEast.turn_left(), Direction::NorthEast);
+    /// ```
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Returns the direction after turning right by 90°.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Direction;
+    ///
+    /// assert_eq!(Direction::North.turn_right(), Direction::East);
+    /// assert_eq!(Direction::NorthWest.turn_right(), Direction::NorthEast);
+    /// ```
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Returns the direction after turning left by 45°.
+    #[must_use]
+    pub const fn turn_slightly_left(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Returns the direction after turning right by 45°.
+    #[must_use]
+    pub const fn turn_slightly_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Determines whether this is one of the four cardinal directions.
+    #[must_use]
+    pub const fn is_cardinal(self) -> bool {
+        matches!(self, Self::North | Self::East | Self::South | Self::West)
+    }
+
+    /// Determines whether this is one of the four diagonal directions.
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        !self.is_cardinal()
+    }
+
+    /// Returns the side of a cell this direction points to, if it is a cardinal direction.
+    #[must_use]
+    pub const fn side(self) -> Option<Side> {
+        match self {
+            Self::North => Some(Side::North),
+            Self::East => Some(Side::East),
+            Self::South => Some(Side::South),
+            Self::West => Some(Side::West),
+            _ => None,
+        }
+    }
+
+    /// Rotates the direction clockwise by the given amount of 45° steps.
+    const fn rotate(self, steps: usize) -> Self {
+        Self::ALL[(self as usize + steps) % Self::ALL.len()]
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::North => write!(f, "north"),
+            Self::NorthEast => write!(f, "north-east"),
+            Self::East => write!(f, "east"),
+            Self::SouthEast => write!(f, "south-east"),
+            Self::South => write!(f, "south"),
+            Self::SouthWest => write!(f, "south-west"),
+            Self::West => write!(f, "west"),
+            Self::NorthWest => write!(f, "north-west"),
+        }
+    }
+}
+
+impl From<Side> for Direction {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::North => Self::North,
+            Side::East => Self::East,
+            Side::South => Self::South,
+            Side::West => Self::West,
+        }
+    }
+}
+
+/// Create a `(dx, dy)` offset tuple from a direction.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::Direction;
+///
+/// let (dx, dy) = Direction::SouthWest.into();
+/// assert_eq!((dx, dy), (-1, 1));
+/// ```
+impl From<Direction> for (isize, isize) {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+/// Create a direction from a character.
+///
+/// Supported are `U`/`D`/`L`/`R`, `N`/`E`/`S`/`W` (both case-insensitive),
+/// the ASCII arrows `^`/`>`/`v`/`<` and the Unicode arrows `↑`, `↗`, `→`, `↘`, `↓`, `↙`, `←` and `↖`.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::{Direction, DirectionParseError};
+///
+/// assert_eq!(Direction::try_from('U'), Ok(Direction::North));
+/// assert_eq!(Direction::try_from('r'), Ok(Direction::East));
+/// assert_eq!(Direction::try_from('S'), Ok(Direction::South));
+/// assert_eq!(Direction::try_from('<'), Ok(Direction::West));
+/// assert_eq!(Direction::try_from('↗'), Ok(Direction::NorthEast));
+/// assert_eq!(Direction::try_from('x'), Err(DirectionParseError::InvalidCharacter('x')));
+/// ```
+impl TryFrom<char> for Direction {
+    type Error = DirectionParseError;
+
+    fn try_from(char: char) -> Result<Self, Self::Error> {
+        match char {
+            'U' | 'u' | 'N' | 'n' | '^' | '↑' => Ok(Self::North),
+            '↗' => Ok(Self::NorthEast),
+            'R' | 'r' | 'E' | 'e' | '>' | '→' => Ok(Self::East),
+            '↘' => Ok(Self::SouthEast),
+            'D' | 'd' | 'S' | 's' | 'v' | '↓' => Ok(Self::South),
+            '↙' => Ok(Self::SouthWest),
+            'L' | 'l' | 'W' | 'w' | '<' | '←' => Ok(Self::West),
+            '↖' => Ok(Self::NorthWest),
+            other => Err(DirectionParseError::InvalidCharacter(other)),
+        }
+    }
+}
+
+/// Create a direction from a `&str`.
+///
+/// In addition to the single characters supported by `TryFrom<char>`,
+/// the abbreviations `NE`, `SE`, `SW` and `NW`, as well as the full names
+/// such as `north` or `south-west` are accepted, regardless of case.
+///
+/// # Examples
+///
+/// ```
+/// use core::str::FromStr;
+/// use grid2d::{Direction, DirectionParseError};
+///
+/// assert_eq!(Direction::from_str("L"), Ok(Direction::West));
+/// assert_eq!(Direction::from_str("ne"), Ok(Direction::NorthEast));
+/// assert_eq!(Direction::from_str("South-West"), Ok(Direction::SouthWest));
+/// assert_eq!(Direction::from_str("northwest"), Ok(Direction::NorthWest));
+/// assert_eq!(Direction::from_str(""), Err(DirectionParseError::Empty));
+/// assert_eq!(Direction::from_str("up"), Err(DirectionParseError::InvalidString));
+/// ```
+impl FromStr for Direction {
+    type Err = DirectionParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut chars = string.chars();
+
+        match (chars.next(), chars.next()) {
+            (None, _) => return Err(DirectionParseError::Empty),
+            (Some(char), None) => return Self::try_from(char),
+            _ => (),
+        }
+
+        Self::ALL
+            .into_iter()
+            .find(|direction| {
+                NAMES[*direction as usize]
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(string))
+            })
+            .ok_or(DirectionParseError::InvalidString)
+    }
+}
+
+/// Accepted multi-character names of the directions, in the order of [`Direction::ALL`].
+const NAMES: [&[&str]; 8] = [
+    &["north"],
+    &["ne", "north-east", "northeast"],
+    &["east"],
+    &["se", "south-east", "southeast"],
+    &["south"],
+    &["sw", "south-west", "southwest"],
+    &["west"],
+    &["nw", "north-west", "northwest"],
+];
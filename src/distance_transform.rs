@@ -0,0 +1,215 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::cast::to_i128;
+use crate::{Coordinate, Direction, Grid, Metric};
+
+impl Grid<bool> {
+    /// Returns the distance from each cell to the nearest `true` cell.
+    ///
+    /// The [`Metric::Euclidean`] distances are squared. Runs in linear time for each metric.
+    /// Returns `None` if no cell is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Metric};
+    ///
+    /// let mask = Grid::try_from(("#.........".chars().map(|c| c == '#'), NonZero::new(5).unwrap())).unwrap();
+    ///
+    /// assert_eq!(
+    ///     mask.distance_transform(Metric::Manhattan).unwrap().as_ref(),
+    ///     [0, 1, 2, 3, 4, 1, 2, 3, 4, 5]
+    /// );
+    /// assert_eq!(
+    ///     mask.distance_transform(Metric::Chebyshev).unwrap().as_ref(),
+    ///     [0, 1, 2, 3, 4, 1, 1, 2, 3, 4]
+    /// );
+    /// assert_eq!(
+    ///     mask.distance_transform(Metric::Euclidean).unwrap().as_ref(),
+    ///     [0, 1, 4, 9, 16, 1, 2, 5, 10, 17]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn distance_transform(&self, metric: Metric) -> Option<Grid<usize>> {
+        self.feature_transform(metric)
+            .map(|features| features.map(|(_, distance)| *distance))
+    }
+
+    /// Returns the nearest `true` cell for each cell, along with its distance.
+    ///
+    /// If several cells are nearest, one of them is chosen.
+    /// See [`Grid::distance_transform`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Metric};
+    ///
+    /// let mask = Grid::try_from(("#.....#..".chars().map(|c| c == '#'), NonZero::new(3).unwrap())).unwrap();
+    /// let features = mask.feature_transform(Metric::Euclidean).unwrap();
+    ///
+    /// assert_eq!(features[Coordinate::new(1, 0)], (Coordinate::new(0, 0), 1));
+    /// assert_eq!(features[Coordinate::new(1, 2)], (Coordinate::new(0, 2), 1));
+    /// assert_eq!(features[Coordinate::new(2, 2)], (Coordinate::new(0, 2), 4));
+    ///
+    /// let empty = Grid::new(NonZero::new(2).unwrap(), NonZero::new(2).unwrap(), || false);
+    /// assert_eq!(empty.feature_transform(Metric::Euclidean), None);
+    /// ```
+    #[must_use]
+    pub fn feature_transform(&self, metric: Metric) -> Option<Grid<(Coordinate, usize)>> {
+        let nearest = match metric {
+            Metric::Manhattan => self.chamfer(&[Direction::West, Direction::North])?,
+            Metric::Chebyshev => self.chamfer(&[
+                Direction::West,
+                Direction::NorthWest,
+                Direction::North,
+                Direction::NorthEast,
+            ])?,
+            Metric::Euclidean => self.lower_envelopes()?,
+        };
+
+        #[expect(unsafe_code)]
+        // SAFETY: There is exactly one feature for each cell of the grid.
+        Some(unsafe { Grid::new_unchecked(self.width(), nearest) })
+    }
+
+    /// Propagates the nearest features forward along the given directions and backward along their opposites.
+    ///
+    /// The distance increases by one for each step, which is exact,
+    /// if the directions are the preceding neighbors of the unit ball of the metric.
+    fn chamfer(&self, forward: &[Direction]) -> Option<Vec<(Coordinate, usize)>> {
+        let mut nearest: Vec<Option<(Coordinate, usize)>> = self
+            .enumerate()
+            .map(|(coordinate, feature)| feature.then_some((coordinate, 0)))
+            .collect();
+        let mut relax = |index: usize, direction: Direction| {
+            let coordinate = Coordinate::from_width_and_index(self.width(), index);
+            let candidate = coordinate
+                .step(direction)
+                .filter(|neighbor| self.encompasses(*neighbor))
+                .and_then(|neighbor| neighbor.as_index(self.width()))
+                .and_then(|neighbor| nearest[neighbor])
+                .map(|(feature, distance)| (feature, distance + 1));
+
+            if let Some((_, distance)) = candidate
+                && nearest[index].is_none_or(|(_, best)| distance < best)
+            {
+                nearest[index] = candidate;
+            }
+        };
+
+        for index in 0..self.len() {
+            for direction in forward {
+                relax(index, *direction);
+            }
+        }
+
+        for index in (0..self.len()).rev() {
+            for direction in forward {
+                relax(index, direction.opposite());
+            }
+        }
+
+        nearest.into_iter().collect()
+    }
+
+    /// Computes the exact squared euclidean feature transform after Felzenszwalb and Huttenlocher.
+    ///
+    /// The first pass finds the nearest feature within each column,
+    /// the second pass finds the lower envelope of the resulting parabolas within each row.
+    fn lower_envelopes(&self) -> Option<Vec<(Coordinate, usize)>> {
+        let (width, height) = (self.width().get(), self.height().get());
+        let cells: &[bool] = self.as_ref();
+        let mut rows: Vec<Option<usize>> = vec![None; cells.len()];
+
+        for x in 0..width {
+            let mut last = None;
+
+            for y in 0..height {
+                if cells[y * width + x] {
+                    last = Some(y);
+                }
+
+                rows[y * width + x] = last;
+            }
+
+            last = None;
+
+            for y in (0..height).rev() {
+                if cells[y * width + x] {
+                    last = Some(y);
+                }
+
+                if let Some(below) = last
+                    && rows[y * width + x].is_none_or(|above| y - above > below - y)
+                {
+                    rows[y * width + x] = last;
+                }
+            }
+        }
+
+        let mut nearest = Vec::with_capacity(cells.len());
+        let mut envelope: Vec<(usize, i128, i128)> = Vec::with_capacity(width);
+
+        for y in 0..height {
+            envelope.clear();
+
+            for (x, row) in rows[y * width..(y + 1) * width].iter().enumerate() {
+                let Some(row) = row else {
+                    continue;
+                };
+                let key = square(y.abs_diff(*row)) + square(x);
+                let start = loop {
+                    let Some(&(previous, previous_key, previous_start)) = envelope.last() else {
+                        break i128::MIN;
+                    };
+                    let start = ceil_div(key - previous_key, 2 * to_i128(x - previous));
+
+                    if start > previous_start {
+                        break start;
+                    }
+
+                    envelope.pop();
+                };
+                envelope.push((x, key, start));
+            }
+
+            let mut segment = 0;
+
+            for x in 0..width {
+                while envelope
+                    .get(segment + 1)
+                    .is_some_and(|(_, _, start)| *start <= to_i128(x))
+                {
+                    segment += 1;
+                }
+
+                let &(column, _, _) = envelope.get(segment)?;
+                let feature = Coordinate::new(column, rows[y * width + column]?);
+                nearest.push((
+                    feature,
+                    Metric::Euclidean.distance(Coordinate::new(x, y), feature),
+                ));
+            }
+        }
+
+        Some(nearest)
+    }
+}
+
+/// Divides and rounds towards positive infinity, given a positive divisor.
+const fn ceil_div(dividend: i128, divisor: i128) -> i128 {
+    dividend.div_euclid(divisor)
+        + if dividend.rem_euclid(divisor) == 0 {
+            0
+        } else {
+            1
+        }
+}
+
+fn square(value: usize) -> i128 {
+    to_i128(value) * to_i128(value)
+}
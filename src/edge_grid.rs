@@ -0,0 +1,303 @@
+use alloc::string::String;
+use core::num::NonZero;
+
+use crate::{Coordinate, Grid, Side};
+
+/// Data attached to the edges between the cells of a grid, such as walls or doors.
+///
+/// Every cell has four edges, one on each [`Side`].
+/// Adjacent cells share the edge between them, so the east edge of a cell is
+/// the same as the west edge of the cell to its right.
+/// The edges on the outer boundary of the grid exist as well.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EdgeGrid<E> {
+    horizontal: Grid<E>,
+    vertical: Grid<E>,
+}
+
+impl<E> EdgeGrid<E> {
+    /// Returns a new edge grid for a grid of the given width and height.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the amount of edges is too large to fit into a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, EdgeGrid, Side};
+    ///
+    /// let width = NonZero::new(3).unwrap();
+    /// let height = NonZero::new(2).unwrap();
+    /// let edges = EdgeGrid::new(width, height, || false);
+    ///
+    /// assert_eq!(edges.width(), width);
+    /// assert_eq!(edges.height(), height);
+    /// assert_eq!(edges.get(Coordinate::new(2, 1), Side::South), Some(&false));
+    /// assert_eq!(edges.get(Coordinate::new(3, 1), Side::South), None);
+    /// ```
+    pub fn new(width: NonZero<usize>, height: NonZero<usize>, initializer: impl Fn() -> E) -> Self {
+        Self::try_new(width, height, initializer).expect("edge grid too large")
+    }
+
+    /// Returns a new edge grid for a grid of the given width and height.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `None` if the amount of edges is too large to fit into a `usize`.
+    pub fn try_new(
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+        initializer: impl Fn() -> E,
+    ) -> Option<Self> {
+        Some(Self {
+            horizontal: Grid::try_new(width, height.checked_add(1)?, &initializer)?,
+            vertical: Grid::try_new(width.checked_add(1)?, height, &initializer)?,
+        })
+    }
+
+    /// Returns a new edge grid matching the dimensions of the given grid.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the amount of edges is too large to fit into a `usize`.
+    pub fn for_grid<T>(grid: &Grid<T>, initializer: impl Fn() -> E) -> Self {
+        Self::new(grid.width(), grid.height(), initializer)
+    }
+
+    /// Returns the width of the grid of cells.
+    #[must_use]
+    pub const fn width(&self) -> NonZero<usize> {
+        self.horizontal.width()
+    }
+
+    /// Returns the height of the grid of cells.
+    #[must_use]
+    pub fn height(&self) -> NonZero<usize> {
+        self.vertical.height()
+    }
+
+    /// Returns the horizontal edges.
+    ///
+    /// The edge at `(x, y)` is the north edge of the cell at `(x, y)`.
+    #[must_use]
+    pub const fn horizontal(&self) -> &Grid<E> {
+        &self.horizontal
+    }
+
+    /// Returns the vertical edges.
+    ///
+    /// The edge at `(x, y)` is the west edge of the cell at `(x, y)`.
+    #[must_use]
+    pub const fn vertical(&self) -> &Grid<E> {
+        &self.vertical
+    }
+
+    /// Returns an Option to a reference of the edge on the given side of a cell.
+    pub fn get(&self, coordinate: impl TryInto<Coordinate>, side: Side) -> Option<&E> {
+        let coordinate = coordinate.try_into().ok()?;
+
+        if !self.encompasses(coordinate) {
+            return None;
+        }
+
+        match side {
+            Side::North => self.horizontal.get(coordinate),
+            Side::South => self.horizontal.get((coordinate.x(), coordinate.y() + 1)),
+            Side::West => self.vertical.get(coordinate),
+            Side::East => self.vertical.get((coordinate.x() + 1, coordinate.y())),
+        }
+    }
+
+    /// Returns an Option to a mutable reference of the edge on the given side of a cell.
+    pub fn get_mut(&mut self, coordinate: impl TryInto<Coordinate>, side: Side) -> Option<&mut E> {
+        let coordinate = coordinate.try_into().ok()?;
+
+        if !self.encompasses(coordinate) {
+            return None;
+        }
+
+        match side {
+            Side::North => self.horizontal.get_mut(coordinate),
+            Side::South => self
+                .horizontal
+                .get_mut((coordinate.x(), coordinate.y() + 1)),
+            Side::West => self.vertical.get_mut(coordinate),
+            Side::East => self.vertical.get_mut((coordinate.x() + 1, coordinate.y())),
+        }
+    }
+
+    /// Returns an Option to a reference of the edge between two orthogonally adjacent cells.
+    ///
+    /// Returns `None` if the cells are not orthogonally adjacent or not on the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, EdgeGrid, Side};
+    ///
+    /// let width = NonZero::new(3).unwrap();
+    /// let height = NonZero::new(2).unwrap();
+    /// let mut walls = EdgeGrid::new(width, height, || false);
+    /// *walls.get_mut(Coordinate::new(1, 0), Side::East).unwrap() = true;
+    ///
+    /// assert_eq!(walls.between(Coordinate::new(1, 0), Coordinate::new(2, 0)), Some(&true));
+    /// assert_eq!(walls.between(Coordinate::new(2, 0), Coordinate::new(1, 0)), Some(&true));
+    /// assert_eq!(walls.between(Coordinate::new(1, 0), Coordinate::new(1, 1)), Some(&false));
+    /// assert_eq!(walls.between(Coordinate::new(0, 0), Coordinate::new(1, 1)), None);
+    /// ```
+    pub fn between(&self, a: impl TryInto<Coordinate>, b: impl TryInto<Coordinate>) -> Option<&E> {
+        let a = a.try_into().ok()?;
+        let b = b.try_into().ok()?;
+
+        if !self.encompasses(b) {
+            return None;
+        }
+
+        self.get(a, side_towards(a, b)?)
+    }
+
+    /// Returns an Option to a mutable reference of the edge between two orthogonally adjacent cells.
+    ///
+    /// Returns `None` if the cells are not orthogonally adjacent or not on the grid.
+    pub fn between_mut(
+        &mut self,
+        a: impl TryInto<Coordinate>,
+        b: impl TryInto<Coordinate>,
+    ) -> Option<&mut E> {
+        let a = a.try_into().ok()?;
+        let b = b.try_into().ok()?;
+
+        if !self.encompasses(b) {
+            return None;
+        }
+
+        self.get_mut(a, side_towards(a, b)?)
+    }
+
+    /// Yields tuples of the sides and references to the edges of the given cell.
+    ///
+    /// Yields nothing if the cell is not on the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, EdgeGrid, Side};
+    ///
+    /// let width = NonZero::new(2).unwrap();
+    /// let height = NonZero::new(2).unwrap();
+    /// let mut walls = EdgeGrid::new(width, height, || false);
+    /// *walls.get_mut(Coordinate::new(0, 0), Side::North).unwrap() = true;
+    ///
+    /// let sides: Vec<_> = walls
+    ///     .edges(Coordinate::new(0, 0))
+    ///     .filter(|(_, wall)| **wall)
+    ///     .map(|(side, _)| side)
+    ///     .collect();
+    /// assert_eq!(sides, [Side::North]);
+    /// assert_eq!(walls.edges(Coordinate::new(2, 0)).count(), 0);
+    /// ```
+    pub fn edges(&self, coordinate: impl TryInto<Coordinate>) -> impl Iterator<Item = (Side, &E)> {
+        let coordinate = coordinate.try_into().ok();
+        Side::ALL
+            .into_iter()
+            .filter_map(move |side| self.get(coordinate?, side).map(|edge| (side, edge)))
+    }
+
+    /// Determines whether the given cell coordinate is on the grid.
+    pub fn encompasses(&self, coordinate: impl TryInto<Coordinate>) -> bool {
+        coordinate.try_into().is_ok_and(|coordinate: Coordinate| {
+            coordinate.x() < self.width().get() && coordinate.y() < self.height().get()
+        })
+    }
+
+    /// Renders the cells and the edges between them as ASCII art.
+    ///
+    /// Edges for which `is_wall` returns `true` are drawn as `-` or `|`, all other edges as blanks.
+    /// Corners are drawn as `+` and every cell is represented by the character returned by `cell`.
+    ///
+    /// Returns `None` if the dimensions of `cells` do not match the edge grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, EdgeGrid, Grid, Side};
+    ///
+    /// let cells = Grid::try_from(("ab.d".chars(), NonZero::new(2).unwrap())).unwrap();
+    /// let mut walls = EdgeGrid::for_grid(&cells, || true);
+    /// *walls.get_mut(Coordinate::new(0, 0), Side::South).unwrap() = false;
+    /// *walls.get_mut(Coordinate::new(0, 1), Side::East).unwrap() = false;
+    ///
+    /// assert_eq!(
+    ///     walls.render(&cells, |wall| *wall, |cell| *cell).unwrap(),
+    ///     "+-+-+\n|a|b|\n+ +-+\n|. d|\n+-+-+"
+    /// );
+    /// ```
+    pub fn render<T>(
+        &self,
+        cells: &Grid<T>,
+        is_wall: impl Fn(&E) -> bool,
+        cell: impl Fn(&T) -> char,
+    ) -> Option<String> {
+        if cells.width() != self.width() || cells.height() != self.height() {
+            return None;
+        }
+
+        let mut string = String::new();
+
+        for y in 0..self.height().get() {
+            self.render_horizontal(&mut string, y, &is_wall);
+            string.push('\n');
+
+            for x in 0..self.width().get() {
+                string.push(if is_wall(&self.vertical[(x, y)]) {
+                    '|'
+                } else {
+                    ' '
+                });
+                string.push(cell(&cells[(x, y)]));
+            }
+
+            let east = &self.vertical[(self.width().get(), y)];
+            string.push(if is_wall(east) { '|' } else { ' ' });
+            string.push('\n');
+        }
+
+        self.render_horizontal(&mut string, self.height().get(), &is_wall);
+        Some(string)
+    }
+
+    fn render_horizontal(&self, string: &mut String, y: usize, is_wall: &impl Fn(&E) -> bool) {
+        for x in 0..self.width().get() {
+            string.push('+');
+            string.push(if is_wall(&self.horizontal[(x, y)]) {
+                '-'
+            } else {
+                ' '
+            });
+        }
+
+        string.push('+');
+    }
+}
+
+impl<E> EdgeGrid<E>
+where
+    E: Default,
+{
+    /// Returns a new edge grid for a type that implements the Default trait.
+    pub fn new_default(width: NonZero<usize>, height: NonZero<usize>) -> Self {
+        Self::new(width, height, E::default)
+    }
+}
+
+/// Returns the side of `from` that faces the orthogonally adjacent cell `to`.
+fn side_towards(from: Coordinate, to: Coordinate) -> Option<Side> {
+    Side::ALL
+        .into_iter()
+        .find(|side| (&from + &side.offset()) == Some(to))
+}
@@ -1,7 +1,11 @@
 pub use self::build_error::BuildError;
 pub use self::coordinate_parse_error::CoordinateParseError;
+pub use self::dimension_mismatch_error::DimensionMismatchError;
+pub use self::direction_parse_error::DirectionParseError;
 pub use self::from_iterable_error::FromIterableError;
 
 mod build_error;
 mod coordinate_parse_error;
+mod dimension_mismatch_error;
+mod direction_parse_error;
 mod from_iterable_error;
@@ -2,8 +2,13 @@ use core::error::Error;
 use core::fmt::{self, Display, Formatter};
 use core::num::ParseIntError;
 
+use crate::Notation;
+
 /// Errors that can occur when parsing a coordinate from a string.
+///
+/// More variants may be added as further notations are supported.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum CoordinateParseError {
     /// The string does not contain two numbers for x and y.
     NotTwoNumbers,
@@ -11,6 +16,27 @@ pub enum CoordinateParseError {
     InvalidXValue(ParseIntError),
     /// The value for the y coordinate is invalid.
     InvalidYValue(ParseIntError),
+    /// The string contains a character that is not valid at this position in the notation.
+    UnexpectedCharacter {
+        /// The notation that failed to parse.
+        notation: Notation,
+        /// The index of the offending character, counted in characters.
+        position: usize,
+        /// The offending character.
+        character: char,
+    },
+    /// The string ended before the notation was complete.
+    UnexpectedEnd {
+        /// The notation that failed to parse.
+        notation: Notation,
+    },
+    /// A component of the notation is zero where counting starts at one, or too large.
+    OutOfRange {
+        /// The notation that failed to parse.
+        notation: Notation,
+        /// The index of the first character of the offending component, counted in characters.
+        position: usize,
+    },
 }
 
 impl Display for CoordinateParseError {
@@ -19,6 +45,23 @@ impl Display for CoordinateParseError {
             Self::NotTwoNumbers => write!(f, "not two numbers"),
             Self::InvalidXValue(error) => write!(f, "invalid x value: {error}"),
             Self::InvalidYValue(error) => write!(f, "invalid y value: {error}"),
+            Self::UnexpectedCharacter {
+                notation,
+                position,
+                character,
+            } => write!(
+                f,
+                "invalid {notation} notation: unexpected character {character:?} at position {position}"
+            ),
+            Self::UnexpectedEnd { notation } => {
+                write!(f, "invalid {notation} notation: unexpected end")
+            }
+            Self::OutOfRange { notation, position } => {
+                write!(
+                    f,
+                    "invalid {notation} notation: value out of range at position {position}"
+                )
+            }
         }
     }
 }
@@ -26,8 +69,8 @@ impl Display for CoordinateParseError {
 impl Error for CoordinateParseError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::NotTwoNumbers => None,
             Self::InvalidXValue(error) | Self::InvalidYValue(error) => Some(error),
+            _ => None,
         }
     }
 }
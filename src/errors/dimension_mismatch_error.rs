@@ -0,0 +1,42 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+/// The error that occurs when combining a grid with a grid, row or column of different dimensions.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DimensionMismatchError {
+    expected: (usize, usize),
+    found: (usize, usize),
+}
+
+impl DimensionMismatchError {
+    /// Creates a new error from the expected and the found width and height.
+    pub(crate) const fn new(expected: (usize, usize), found: (usize, usize)) -> Self {
+        Self { expected, found }
+    }
+
+    /// Returns the width and height of the grid that the other operand was expected to match.
+    #[must_use]
+    pub const fn expected(&self) -> (usize, usize) {
+        self.expected
+    }
+
+    /// Returns the width and height of the mismatching operand.
+    ///
+    /// A row spans the height of the grid and a column spans its width.
+    #[must_use]
+    pub const fn found(&self) -> (usize, usize) {
+        self.found
+    }
+}
+
+impl Display for DimensionMismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected dimensions {}x{}, found {}x{}",
+            self.expected.0, self.expected.1, self.found.0, self.found.1
+        )
+    }
+}
+
+impl Error for DimensionMismatchError {}
@@ -0,0 +1,25 @@
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// Errors that can occur when parsing a direction from a character or string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DirectionParseError {
+    /// The string is empty.
+    Empty,
+    /// The character does not denote a direction.
+    InvalidCharacter(char),
+    /// The string does not denote a direction.
+    InvalidString,
+}
+
+impl Display for DirectionParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty string"),
+            Self::InvalidCharacter(char) => write!(f, "invalid direction character: {char:?}"),
+            Self::InvalidString => write!(f, "invalid direction"),
+        }
+    }
+}
+
+impl Error for DirectionParseError {}
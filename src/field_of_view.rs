@@ -0,0 +1,218 @@
+use alloc::vec;
+use core::ops::RangeInclusive;
+
+use crate::cast::to_i128;
+use crate::{Bresenham, Coordinate, CoordinateSet, Grid};
+
+/// Determines which cells count as visible in a field of view.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Visibility {
+    /// A transparent cell is visible from the origin, if and only if the origin is visible from it.
+    #[default]
+    Symmetric,
+    /// A transparent cell is visible, if light reaches any part of its central column or row,
+    /// which reveals more cells along the edges of obstacles at the cost of symmetry.
+    Permissive,
+}
+
+impl<T> Grid<T> {
+    /// Computes the cells visible from the origin using shadowcasting.
+    ///
+    /// Opaque cells block the view, but are visible themselves if light reaches them.
+    /// Only cells within the euclidean radius around the origin are visible.
+    /// The origin is always visible, unless it is outside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Visibility};
+    ///
+    /// let grid = Grid::try_from((
+    ///     "..#..\
+    ///      .....\
+    ///      ..@..\
+    ///      .#...\
+    ///      .....".chars(),
+    ///     NonZero::new(5).unwrap(),
+    /// ))
+    /// .unwrap();
+    /// let fov = grid.field_of_view((2, 2), 3, Visibility::Symmetric, |cell| *cell == '#');
+    /// let rows: Vec<String> = grid
+    ///     .enumerate()
+    ///     .map(|(coordinate, cell)| if fov.contains(coordinate) { *cell } else { ' ' })
+    ///     .collect::<Vec<_>>()
+    ///     .chunks(5)
+    ///     .map(|row| row.iter().collect())
+    ///     .collect();
+    ///
+    /// assert_eq!(rows, ["..#..", ".....", "..@..", ".#...", " ...."]);
+    ///
+    /// let grid = Grid::try_from(("...#.@....".chars(), NonZero::new(5).unwrap())).unwrap();
+    /// let opaque = |cell: &char| *cell == '#';
+    ///
+    /// assert!(!grid.field_of_view((0, 1), 5, Visibility::Symmetric, opaque).contains((4, 0)));
+    /// assert!(grid.field_of_view((0, 1), 5, Visibility::Permissive, opaque).contains((4, 0)));
+    /// assert!(!grid.field_of_view((4, 0), 5, Visibility::Symmetric, opaque).contains((0, 1)));
+    /// ```
+    pub fn field_of_view(
+        &self,
+        origin: impl TryInto<Coordinate>,
+        radius: usize,
+        visibility: Visibility,
+        opaque: impl Fn(&T) -> bool,
+    ) -> CoordinateSet {
+        let mut visible = CoordinateSet::for_grid(self);
+
+        let Some(origin) = origin
+            .try_into()
+            .ok()
+            .filter(|origin| self.encompasses(*origin))
+        else {
+            return visible;
+        };
+
+        visible.insert(origin);
+
+        let radius_squared = to_i128(radius).saturating_mul(to_i128(radius));
+
+        for quadrant in 0..4 {
+            let mut rows = vec![Row {
+                depth: 1,
+                start: (-1, 1),
+                end: (1, 1),
+            }];
+
+            while let Some(mut row) = rows.pop() {
+                if row.depth > to_i128(radius) {
+                    continue;
+                }
+
+                let mut previous = None;
+
+                for column in row.columns() {
+                    let coordinate = transform(origin, quadrant, row.depth, column)
+                        .filter(|coordinate| self.encompasses(*coordinate));
+                    let wall = coordinate
+                        .and_then(|coordinate| self.get(coordinate))
+                        .is_none_or(&opaque);
+                    let within = row.depth * row.depth + column * column <= radius_squared;
+                    let lit =
+                        wall || visibility == Visibility::Permissive || row.is_symmetric(column);
+
+                    if let Some(coordinate) = coordinate
+                        && within
+                        && lit
+                    {
+                        visible.insert(coordinate);
+                    }
+
+                    if previous == Some(true) && !wall {
+                        row.start = slope(row.depth, column);
+                    }
+
+                    if previous == Some(false) && wall {
+                        rows.push(Row {
+                            depth: row.depth + 1,
+                            start: row.start,
+                            end: slope(row.depth, column),
+                        });
+                    }
+
+                    previous = Some(wall);
+                }
+
+                if previous == Some(false) {
+                    rows.push(Row {
+                        depth: row.depth + 1,
+                        ..row
+                    });
+                }
+            }
+        }
+
+        visible
+    }
+
+    /// Determines whether the straight line between two coordinates is free of opaque cells.
+    ///
+    /// The end points themselves may be opaque. The line of sight exists, if the cells of the
+    /// [`Bresenham`] line in either direction are transparent, so that the result is symmetric.
+    /// Returns false, if either coordinate is outside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let grid = Grid::try_from(("....#....".chars(), NonZero::new(3).unwrap())).unwrap();
+    ///
+    /// assert!(grid.line_of_sight((0, 0), (2, 0), |cell| *cell == '#'));
+    /// assert!(grid.line_of_sight((1, 0), (1, 1), |cell| *cell == '#'));
+    /// assert!(!grid.line_of_sight((1, 0), (1, 2), |cell| *cell == '#'));
+    /// assert!(!grid.line_of_sight((0, 0), (2, 2), |cell| *cell == '#'));
+    /// assert!(!grid.line_of_sight((0, 0), (3, 0), |cell| *cell == '#'));
+    /// ```
+    pub fn line_of_sight(
+        &self,
+        from: impl TryInto<Coordinate>,
+        to: impl TryInto<Coordinate>,
+        opaque: impl Fn(&T) -> bool,
+    ) -> bool {
+        let (Ok(from), Ok(to)) = (from.try_into(), to.try_into()) else {
+            return false;
+        };
+        let clear = |line: Bresenham| {
+            line.filter(|coordinate| *coordinate != from && *coordinate != to)
+                .all(|coordinate| self.get(coordinate).is_some_and(|cell| !opaque(cell)))
+        };
+
+        self.encompasses(from)
+            && self.encompasses(to)
+            && (clear(Bresenham::new(from, to)) || clear(Bresenham::new(to, from)))
+    }
+}
+
+/// A row of cells at a fixed depth within a quadrant, bounded by the slopes of the visible sector.
+#[derive(Clone, Copy)]
+struct Row {
+    depth: i128,
+    start: (i128, i128),
+    end: (i128, i128),
+}
+
+impl Row {
+    /// Returns the columns whose centers lie within the sector, rounding ties outwards.
+    const fn columns(&self) -> RangeInclusive<i128> {
+        let (start, start_denominator) = self.start;
+        let (end, end_denominator) = self.end;
+        let first = (2 * self.depth * start + start_denominator).div_euclid(2 * start_denominator);
+        let last = -(end_denominator - 2 * self.depth * end).div_euclid(2 * end_denominator);
+        first..=last
+    }
+
+    /// Determines whether the center of the cell in the given column lies within the sector.
+    const fn is_symmetric(&self, column: i128) -> bool {
+        column * self.start.1 >= self.depth * self.start.0
+            && column * self.end.1 <= self.depth * self.end.0
+    }
+}
+
+/// Returns the slope towards the left edge of the cell in the given depth and column.
+const fn slope(depth: i128, column: i128) -> (i128, i128) {
+    (2 * column - 1, 2 * depth)
+}
+
+/// Maps the depth and column within a quadrant to a coordinate.
+fn transform(origin: Coordinate, quadrant: usize, depth: i128, column: i128) -> Option<Coordinate> {
+    let (dx, dy) = match quadrant {
+        0 => (column, -depth),
+        1 => (depth, column),
+        2 => (column, depth),
+        _ => (-depth, column),
+    };
+    let x = usize::try_from(to_i128(origin.x()) + dx).ok()?;
+    let y = usize::try_from(to_i128(origin.y()) + dy).ok()?;
+    Some(Coordinate::new(x, y))
+}
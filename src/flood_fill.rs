@@ -0,0 +1,154 @@
+use alloc::vec;
+
+use crate::{Connectivity, Coordinate, CoordinateSet, Grid};
+
+impl<T> Grid<T> {
+    /// Returns the region of cells matching the predicate that is connected to `start`.
+    ///
+    /// The region is empty if `start` is not on the grid or does not match the predicate.
+    /// The fill is iterative, so large regions do not exhaust the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Coordinate, Grid};
+    ///
+    /// let grid = Grid::try_from(("..#.#.#..".chars(), NonZero::new(3).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.flood_fill((0, 0), Connectivity::Four, |cell| *cell == '.').len(), 3);
+    /// assert_eq!(grid.flood_fill((0, 0), Connectivity::Eight, |cell| *cell == '.').len(), 6);
+    /// assert!(grid.flood_fill((2, 0), Connectivity::Four, |cell| *cell == '.').is_empty());
+    ///
+    /// let outside = Coordinate::<i64>::from((-1, 0));
+    /// assert!(grid.flood_fill(outside, Connectivity::Four, |cell| *cell == '.').is_empty());
+    /// ```
+    pub fn flood_fill(
+        &self,
+        start: impl TryInto<Coordinate>,
+        connectivity: Connectivity,
+        predicate: impl Fn(&T) -> bool,
+    ) -> CoordinateSet {
+        let mut region = CoordinateSet::for_grid(self);
+
+        let Some(start) = start
+            .try_into()
+            .ok()
+            .filter(|start| self.get(*start).is_some_and(&predicate))
+        else {
+            return region;
+        };
+
+        region.insert(start);
+        let mut stack = vec![start];
+
+        while let Some(coordinate) = stack.pop() {
+            for neighbor in coordinate.neighbors_with(connectivity) {
+                if self.get(neighbor).is_some_and(&predicate) && region.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Labels the connected components of the grid.
+    ///
+    /// Two adjacent cells belong to the same component if `eq` returns `true` for their values.
+    /// Returns a grid of component IDs and the amount of components.
+    /// IDs are assigned in row-major order of the components' first cells, starting at zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::try_from(("aabbabccb".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let (labels, count) = grid.label_components(Connectivity::Four, PartialEq::eq);
+    ///
+    /// assert_eq!(count, 4);
+    /// assert_eq!(labels.iter().copied().collect::<Vec<_>>(), [0, 0, 1, 2, 0, 1, 3, 3, 1]);
+    ///
+    /// let checkers = Grid::try_from(("abba".chars(), NonZero::new(2).unwrap())).unwrap();
+    /// assert_eq!(checkers.label_components(Connectivity::Four, PartialEq::eq).1, 4);
+    /// assert_eq!(checkers.label_components(Connectivity::Eight, PartialEq::eq).1, 2);
+    /// ```
+    pub fn label_components(
+        &self,
+        connectivity: Connectivity,
+        eq: impl Fn(&T, &T) -> bool,
+    ) -> (Grid<usize>, usize) {
+        let mut labels = Grid::new(self.width(), self.height(), || usize::MAX);
+        let mut count = 0;
+        let mut stack = vec![];
+
+        for (start, _) in self.enumerate() {
+            let Some(label) = labels.get_mut(start).filter(|label| **label == usize::MAX) else {
+                continue;
+            };
+
+            *label = count;
+            stack.push(start);
+
+            while let Some(coordinate) = stack.pop() {
+                let Some(value) = self.get(coordinate) else {
+                    continue;
+                };
+
+                for neighbor in coordinate.neighbors_with(connectivity) {
+                    if self.get(neighbor).is_some_and(|other| eq(value, other))
+                        && let Some(label) = labels.get_mut(neighbor)
+                        && *label == usize::MAX
+                    {
+                        *label = count;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+
+            count += 1;
+        }
+
+        (labels, count)
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Replaces the region of cells matching the predicate that is connected to `start` with `value`.
+    ///
+    /// Returns the replaced region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Grid};
+    ///
+    /// let mut grid = Grid::try_from(("..#.#.#..".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// grid.flood_fill_mut((2, 2), Connectivity::Four, |cell| *cell == '.', '~');
+    ///
+    /// assert_eq!(grid.iter().collect::<String>(), "..#.#~#~~");
+    /// ```
+    pub fn flood_fill_mut(
+        &mut self,
+        start: impl TryInto<Coordinate>,
+        connectivity: Connectivity,
+        predicate: impl Fn(&T) -> bool,
+        value: T,
+    ) -> CoordinateSet {
+        let region = self.flood_fill(start, connectivity, predicate);
+
+        for coordinate in region.iter() {
+            if let Some(cell) = self.get_mut(coordinate) {
+                cell.clone_from(&value);
+            }
+        }
+
+        region
+    }
+}
@@ -6,7 +6,10 @@ use core::fmt::{self, Display, Formatter};
 use core::num::NonZero;
 use core::ops::{Deref, DerefMut, Index, IndexMut};
 
-use crate::{Coordinate, FromIterableError, GridBuilder};
+use crate::{
+    Bresenham, Coordinate, DimensionMismatchError, FromIterableError, GridBuilder, GridView, Rect,
+    Supercover,
+};
 
 /// A two-dimensional grid of arbitrary cell content.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -138,7 +141,7 @@ impl<T> Grid<T> {
     ///
     /// ```
     /// use std::num::NonZero;
-    /// use grid2d::Grid;
+    /// use grid2d::{Coordinate, Coordinate16, Grid};
     ///
     /// let grid = Grid::try_from(("Hello world!".chars(), NonZero::new(4).unwrap())).unwrap();
     /// assert_eq!(grid.get((0, 0)).unwrap(), &'H');
@@ -157,20 +160,26 @@ impl<T> Grid<T> {
     /// assert_eq!(grid.get((3, 2)).unwrap(), &'!');
     /// assert_eq!(grid.get((4, 2)), None);
     /// assert_eq!(grid.get((0, 3)), None);
+    ///
+    /// // Coordinates with other component types are accepted as well.
+    /// assert_eq!(grid.get(Coordinate16::from((1, 2))).unwrap(), &'l');
+    /// assert_eq!(grid.get(Coordinate::<i64>::from((-1, 2))), None);
     /// ```
     #[inline]
-    pub fn get(&self, coordinate: impl Into<Coordinate>) -> Option<&T> {
+    pub fn get(&self, coordinate: impl TryInto<Coordinate>) -> Option<&T> {
         coordinate
-            .into()
+            .try_into()
+            .ok()?
             .as_index(self.width)
             .and_then(|index| self.items.get(index))
     }
 
     /// Returns an Option to a mutable reference of the cell content at the given coordinate.
     #[inline]
-    pub fn get_mut(&mut self, coordinate: impl Into<Coordinate>) -> Option<&mut T> {
+    pub fn get_mut(&mut self, coordinate: impl TryInto<Coordinate>) -> Option<&mut T> {
         coordinate
-            .into()
+            .try_into()
+            .ok()?
             .as_index(self.width)
             .and_then(|index| self.items.get_mut(index))
     }
@@ -190,7 +199,7 @@ impl<T> Grid<T> {
     ///
     /// ```
     /// use std::num::NonZero;
-    /// use grid2d::{Grid, Coordinate};
+    /// use grid2d::{Coordinate, Coordinate16, Grid};
     ///
     /// let width = NonZero::new(3).unwrap();
     /// let height = NonZero::new(4).unwrap();
@@ -234,13 +243,152 @@ impl<T> Grid<T> {
             .map(|(index, item)| (Coordinate::from_width_and_index(self.width, index), item))
     }
 
+    /// Returns a grid of the same dimensions with the function applied to each item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let grid = Grid::try_from(("#..#".chars(), NonZero::new(2).unwrap())).unwrap();
+    /// let walls = grid.map(|cell| *cell == '#');
+    ///
+    /// assert_eq!(walls.as_ref(), [true, false, false, true]);
+    /// assert_eq!(walls.width(), grid.width());
+    /// ```
+    pub fn map<U>(&self, function: impl FnMut(&T) -> U) -> Grid<U> {
+        let items = self.items.iter().map(function).collect();
+        #[expect(unsafe_code)]
+        // SAFETY: There is exactly one mapped item for each item of the grid.
+        unsafe {
+            Grid::new_unchecked(self.width, items)
+        }
+    }
+
+    /// Returns a grid of the same dimensions with the function applied to each coordinate and item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let grid = Grid::try_from(([1, 2, 3, 4], NonZero::new(2).unwrap())).unwrap();
+    /// let weighted = grid.map_with_coordinate(|coordinate, value| coordinate.y() * 10 + value);
+    ///
+    /// assert_eq!(weighted.as_ref(), [1, 2, 13, 14]);
+    /// ```
+    pub fn map_with_coordinate<U>(&self, mut function: impl FnMut(Coordinate, &T) -> U) -> Grid<U> {
+        let items = self
+            .enumerate()
+            .map(|(coordinate, item)| function(coordinate, item))
+            .collect();
+        #[expect(unsafe_code)]
+        // SAFETY: There is exactly one mapped item for each item of the grid.
+        unsafe {
+            Grid::new_unchecked(self.width, items)
+        }
+    }
+
+    /// Returns a grid of the same dimensions with the fallible function applied to each item.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by the function in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let grid = Grid::try_from(("1234".chars(), NonZero::new(2).unwrap())).unwrap();
+    /// let digits = grid.try_map(|cell| cell.to_digit(10).ok_or(*cell));
+    ///
+    /// assert_eq!(digits.unwrap().as_ref(), [1, 2, 3, 4]);
+    ///
+    /// let grid = Grid::try_from(("1x3y".chars(), NonZero::new(2).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.try_map(|cell| cell.to_digit(10).ok_or(*cell)), Err('x'));
+    /// ```
+    pub fn try_map<U, E>(&self, function: impl FnMut(&T) -> Result<U, E>) -> Result<Grid<U>, E> {
+        let items = self.items.iter().map(function).collect::<Result<_, _>>()?;
+        #[expect(unsafe_code)]
+        // SAFETY: There is exactly one mapped item for each item of the grid.
+        Ok(unsafe { Grid::new_unchecked(self.width, items) })
+    }
+
+    /// Returns a grid with the function applied to the items at the same coordinates of both grids.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DimensionMismatchError`] if the grids differ in width or height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let heights = Grid::try_from(([3, 1, 4, 1], NonZero::new(2).unwrap())).unwrap();
+    /// let water = Grid::try_from(([0, 2, 0, 3], NonZero::new(2).unwrap())).unwrap();
+    /// let levels = heights.zip_with(&water, |height, water| height + water).unwrap();
+    ///
+    /// assert_eq!(levels.as_ref(), [3, 3, 4, 4]);
+    ///
+    /// let column = Grid::try_from(([1, 2], NonZero::new(1).unwrap())).unwrap();
+    /// let error = heights.zip_with(&column, |height, other| height + other).unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "expected dimensions 2x2, found 1x2");
+    /// ```
+    pub fn zip_with<U, V>(
+        &self,
+        other: &Grid<U>,
+        mut function: impl FnMut(&T, &U) -> V,
+    ) -> Result<Grid<V>, DimensionMismatchError> {
+        self.check_dimensions(other)?;
+
+        let items = self
+            .items
+            .iter()
+            .zip(other.iter())
+            .map(|(item, other)| function(item, other))
+            .collect();
+        #[expect(unsafe_code)]
+        // SAFETY: Both grids have the same dimensions and one item is combined for each cell.
+        Ok(unsafe { Grid::new_unchecked(self.width, items) })
+    }
+
+    /// Consumes the grid and returns a grid of the same dimensions with the function applied to each item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let grid = Grid::try_from((["a", "bc"].map(String::from), NonZero::new(1).unwrap())).unwrap();
+    /// let lengths = grid.into_map(|text| text.len());
+    ///
+    /// assert_eq!(lengths.as_ref(), [1, 2]);
+    /// ```
+    pub fn into_map<U>(self, function: impl FnMut(T) -> U) -> Grid<U> {
+        let items = self.items.into_iter().map(function).collect();
+        #[expect(unsafe_code)]
+        // SAFETY: There is exactly one mapped item for each item of the grid.
+        unsafe {
+            Grid::new_unchecked(self.width, items)
+        }
+    }
+
     /// Yields tuples of Coordinate and reference to the grid's items that are neighbors of the given coordinate.
     ///
     /// # Examples
     ///
     /// ```
     /// use std::num::NonZero;
-    /// use grid2d::{Grid, Coordinate};
+    /// use grid2d::{Coordinate, Coordinate16, Grid};
     ///
     /// let width = NonZero::new(3).unwrap();
     /// let height = NonZero::new(4).unwrap();
@@ -273,10 +421,12 @@ impl<T> Grid<T> {
     /// assert_eq!(grid.neighbors(Coordinate::new(2, 1)).count(), 5);
     /// assert_eq!(grid.neighbors(Coordinate::new(2, 2)).count(), 5);
     /// assert_eq!(grid.neighbors(Coordinate::new(2, 3)).count(), 3);
+    /// assert_eq!(grid.neighbors(Coordinate16::from_components(1, 1)).count(), 8);
+    /// assert_eq!(grid.neighbors(Coordinate::<i64>::from_components(-1, 1)).count(), 0);
     /// ```
     pub fn neighbors(
         &self,
-        coordinate: impl Into<Coordinate>,
+        coordinate: impl TryInto<Coordinate>,
     ) -> impl Iterator<Item = (Coordinate, &T)> {
         self.neighbors_internal(self.neighbor_coordinates(coordinate))
     }
@@ -286,14 +436,15 @@ impl<T> Grid<T> {
         &self,
         neighbors: Vec<Coordinate>,
     ) -> impl Iterator<Item = (Coordinate, &T)> {
-        self.enumerate()
-            .filter(move |(position, _)| neighbors.iter().any(|neighbor| neighbor == position))
+        neighbors
+            .into_iter()
+            .filter_map(|neighbor| self.get(neighbor).map(|item| (neighbor, item)))
     }
 
     /// Yields tuples of Coordinate and mutable reference to the grid's items that are neighbors of the given coordinate.
     pub fn neighbors_mut(
         &mut self,
-        coordinate: impl Into<Coordinate>,
+        coordinate: impl TryInto<Coordinate>,
     ) -> impl Iterator<Item = (Coordinate, &mut T)> {
         self.neighbors_mut_internal(self.neighbor_coordinates(coordinate))
     }
@@ -303,8 +454,7 @@ impl<T> Grid<T> {
         &mut self,
         neighbors: Vec<Coordinate>,
     ) -> impl Iterator<Item = (Coordinate, &mut T)> {
-        self.enumerate_mut()
-            .filter(move |(position, _)| neighbors.iter().any(|neighbor| neighbor == position))
+        self.disjoint_mut(neighbors)
     }
 
     /// Yields the rows of the grid.
@@ -361,24 +511,301 @@ impl<T> Grid<T> {
         })
     }
 
+    /// Yields tuples of Coordinate and reference to the grid's items on the line from `start` to `end`.
+    ///
+    /// The cells are determined by [`Bresenham`]'s algorithm and clipped to the grid,
+    /// without walking the cells outside of it, so far away end points are cheap.
+    /// Use [`Grid::supercover`] to visit every cell that the line touches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid};
+    ///
+    /// let grid = Grid::try_from((0u8..12, NonZero::new(4).unwrap())).unwrap();
+    /// let items: Vec<_> = grid.line((0, 0), (3, 2)).map(|(_, item)| *item).collect();
+    ///
+    /// assert_eq!(items, [0, 5, 6, 11]);
+    /// assert_eq!(grid.line((2, 2), (6, 2)).count(), 2);
+    /// assert_eq!(grid.line((usize::MAX, usize::MAX), (0, 0)).count(), 3);
+    /// ```
+    pub fn line(
+        &self,
+        start: impl TryInto<Coordinate>,
+        end: impl TryInto<Coordinate>,
+    ) -> impl Iterator<Item = (Coordinate, &T)> {
+        let bounds = self.bounds();
+        end_points(start, end)
+            .into_iter()
+            .flat_map(move |(start, end)| Bresenham::clipped(start, end, &bounds))
+            .filter_map(|coordinate| self.get(coordinate).map(|item| (coordinate, item)))
+    }
+
+    /// Yields tuples of Coordinate and mutable reference to the grid's items on the line from `start` to `end`.
+    ///
+    /// The cells are determined by [`Bresenham`]'s algorithm and clipped to the grid.
+    /// There are no cells if an end point cannot be converted into a [`Coordinate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let mut grid = Grid::new(NonZero::new(4).unwrap(), NonZero::new(3).unwrap(), || '.');
+    ///
+    /// for (_, item) in grid.line_mut((3, 0), (0, 2)) {
+    ///     *item = '#';
+    /// }
+    ///
+    /// assert_eq!(grid.to_string(), ".\t.\t.\t#\n.\t#\t#\t.\n#\t.\t.\t.");
+    /// ```
+    pub fn line_mut(
+        &mut self,
+        start: impl TryInto<Coordinate>,
+        end: impl TryInto<Coordinate>,
+    ) -> impl Iterator<Item = (Coordinate, &mut T)> {
+        let bounds = self.bounds();
+        self.disjoint_mut(
+            end_points(start, end)
+                .into_iter()
+                .flat_map(move |(start, end)| Bresenham::clipped(start, end, &bounds)),
+        )
+    }
+
+    /// Yields tuples of Coordinate and reference to the grid's items that the line from `start` to `end` touches.
+    ///
+    /// The cells are determined by [`Supercover`] and clipped to the grid,
+    /// without walking the cells outside of it, so far away end points are cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid};
+    ///
+    /// let grid = Grid::try_from((0u8..12, NonZero::new(4).unwrap())).unwrap();
+    /// let items: Vec<_> = grid.supercover((0, 0), (3, 1)).map(|(_, item)| *item).collect();
+    ///
+    /// assert_eq!(items, [0, 1, 2, 5, 6, 7]);
+    /// assert_eq!(grid.supercover((2, 2), (6, 2)).count(), 2);
+    /// assert_eq!(grid.supercover((usize::MAX, usize::MAX), (0, 0)).count(), 8);
+    /// ```
+    pub fn supercover(
+        &self,
+        start: impl TryInto<Coordinate>,
+        end: impl TryInto<Coordinate>,
+    ) -> impl Iterator<Item = (Coordinate, &T)> {
+        let bounds = self.bounds();
+        end_points(start, end)
+            .into_iter()
+            .flat_map(move |(start, end)| Supercover::clipped(start, end, &bounds))
+            .filter_map(|coordinate| self.get(coordinate).map(|item| (coordinate, item)))
+    }
+
+    /// Yields tuples of Coordinate and mutable reference to the grid's items that the line from `start` to `end` touches.
+    ///
+    /// The cells are determined by [`Supercover`] and clipped to the grid.
+    /// There are no cells if an end point cannot be converted into a [`Coordinate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let mut grid = Grid::new(NonZero::new(4).unwrap(), NonZero::new(2).unwrap(), || '.');
+    ///
+    /// for (_, item) in grid.supercover_mut((0, 0), (3, 1)) {
+    ///     *item = '#';
+    /// }
+    ///
+    /// assert_eq!(grid.to_string(), "#\t#\t#\t.\n.\t#\t#\t#");
+    /// ```
+    pub fn supercover_mut(
+        &mut self,
+        start: impl TryInto<Coordinate>,
+        end: impl TryInto<Coordinate>,
+    ) -> impl Iterator<Item = (Coordinate, &mut T)> {
+        let bounds = self.bounds();
+        self.disjoint_mut(
+            end_points(start, end)
+                .into_iter()
+                .flat_map(move |(start, end)| Supercover::clipped(start, end, &bounds)),
+        )
+    }
+
     /// Returns the coordinates that are neighbors of the given coordinate.
-    pub fn neighbor_coordinates(&self, coordinate: impl Into<Coordinate>) -> Vec<Coordinate> {
-        coordinate
-            .into()
-            .neighbors()
-            .filter(|coordinate| self.encompasses(*coordinate))
-            .collect()
+    ///
+    /// Returns no coordinates if the given coordinate cannot be converted into a [`Coordinate`].
+    pub fn neighbor_coordinates(&self, coordinate: impl TryInto<Coordinate>) -> Vec<Coordinate> {
+        coordinate.try_into().map_or_else(
+            |_| Vec::new(),
+            |coordinate| {
+                coordinate
+                    .neighbors()
+                    .filter(|coordinate| self.encompasses(*coordinate))
+                    .collect()
+            },
+        )
+    }
+
+    /// Returns the rectangle that covers the whole grid.
+    #[must_use]
+    pub fn bounds(&self) -> Rect {
+        Rect::new(Coordinate::new(0, 0), self.width, self.height())
+    }
+
+    /// Returns a view of the given section of the grid.
+    ///
+    /// The section is clipped to the grid.
+    /// Returns `None` if the section does not overlap with the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Rect};
+    ///
+    /// let grid = Grid::try_from((0u8..12, NonZero::new(4).unwrap())).unwrap();
+    /// let view = grid.view(Rect::from_corners((2, 1), (5, 5))).unwrap();
+    ///
+    /// assert_eq!(view.to_string(), "6\t7\n10\t11");
+    /// assert!(grid.view(Rect::from_corners((4, 0), (5, 5))).is_none());
+    /// ```
+    #[must_use]
+    pub fn view(&self, rect: Rect) -> Option<GridView<'_, T>> {
+        rect.clip_to(self).map(|rect| GridView::new(self, rect))
+    }
+
+    /// Yields tuples of Coordinate and reference to the grid's items within the given section.
+    ///
+    /// The section is clipped to the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Rect};
+    ///
+    /// let grid = Grid::try_from((0u8..12, NonZero::new(4).unwrap())).unwrap();
+    /// let items: Vec<_> = grid.enumerate_rect(Rect::from_corners((3, 1), (5, 2))).collect();
+    ///
+    /// assert_eq!(items, [(Coordinate::new(3, 1), &7), (Coordinate::new(3, 2), &11)]);
+    /// ```
+    pub fn enumerate_rect(&self, rect: Rect) -> impl Iterator<Item = (Coordinate, &T)> {
+        let (origin, width, height) = self.clipped(rect);
+        self.items
+            .chunks(self.width.get())
+            .skip(origin.y())
+            .take(height)
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                row[origin.x()..origin.x() + width]
+                    .iter()
+                    .enumerate()
+                    .map(move |(x, item)| (Coordinate::new(origin.x() + x, origin.y() + y), item))
+            })
+    }
+
+    /// Yields tuples of Coordinate and mutable reference to the grid's items within the given section.
+    ///
+    /// The section is clipped to the grid.
+    pub fn enumerate_rect_mut(&mut self, rect: Rect) -> impl Iterator<Item = (Coordinate, &mut T)> {
+        let (origin, width, height) = self.clipped(rect);
+        self.items
+            .chunks_mut(self.width.get())
+            .skip(origin.y())
+            .take(height)
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                row[origin.x()..origin.x() + width]
+                    .iter_mut()
+                    .enumerate()
+                    .map(move |(x, item)| (Coordinate::new(origin.x() + x, origin.y() + y), item))
+            })
+    }
+
+    /// Yields tuples of Coordinate and mutable reference to the items at the given coordinates.
+    ///
+    /// Coordinates that are not on the grid are skipped.
+    /// The remaining coordinates are yielded in the given order and must be distinct.
+    fn disjoint_mut(
+        &mut self,
+        coordinates: impl IntoIterator<Item = Coordinate>,
+    ) -> impl Iterator<Item = (Coordinate, &mut T)> {
+        let mut targets: Vec<(usize, usize, Coordinate)> = coordinates
+            .into_iter()
+            .filter(|coordinate| self.encompasses_internal(*coordinate))
+            .filter_map(|coordinate| {
+                coordinate
+                    .as_index(self.width)
+                    .map(|index| (index, coordinate))
+            })
+            .enumerate()
+            .map(|(order, (index, coordinate))| (index, order, coordinate))
+            .collect();
+        targets.sort_unstable_by_key(|(index, _, _)| *index);
+
+        let mut items: Vec<Option<(Coordinate, &mut T)>> = Vec::new();
+        items.resize_with(targets.len(), || None);
+        let mut rest = &mut *self.items;
+        let mut offset = 0;
+
+        for (index, order, coordinate) in targets {
+            let (item, tail) = rest[index - offset..]
+                .split_first_mut()
+                .expect("coordinates are distinct and on the grid");
+            items[order] = Some((coordinate, item));
+            rest = tail;
+            offset = index + 1;
+        }
+
+        items.into_iter().flatten()
+    }
+
+    /// Returns the origin, width and height of the part of `rect` that lies on the grid.
+    ///
+    /// Width and height are zero if `rect` does not overlap with the grid.
+    fn clipped(&self, rect: Rect) -> (Coordinate, usize, usize) {
+        rect.clip_to(self)
+            .map_or((Coordinate::new(0, 0), 0, 0), |rect| {
+                (rect.origin(), rect.width().get(), rect.height().get())
+            })
     }
 
     /// Determines whether the given coordinate is on the grid.
-    pub fn encompasses(&self, coordinate: impl Into<Coordinate>) -> bool {
-        self.encompasses_internal(coordinate.into())
+    pub fn encompasses(&self, coordinate: impl TryInto<Coordinate>) -> bool {
+        coordinate
+            .try_into()
+            .is_ok_and(|coordinate| self.encompasses_internal(coordinate))
     }
 
     #[inline]
     fn encompasses_internal(&self, coordinate: Coordinate) -> bool {
         coordinate.x() < self.width.get() && coordinate.y() < self.height().get()
     }
+
+    /// Returns an error if the other grid differs in width or height.
+    pub(crate) fn check_dimensions<U>(
+        &self,
+        other: &Grid<U>,
+    ) -> Result<(), DimensionMismatchError> {
+        let expected = (self.width().get(), self.height().get());
+        let found = (other.width().get(), other.height().get());
+
+        if expected == found {
+            Ok(())
+        } else {
+            Err(DimensionMismatchError::new(expected, found))
+        }
+    }
+
+    /// Exchanges width and height, keeping the cells in place.
+    pub(crate) fn swap_dimensions(&mut self) {
+        self.width = self.height();
+    }
 }
 
 impl<T> Grid<T>
@@ -391,6 +818,52 @@ where
     }
 }
 
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Sets all cells within the given section to `value`.
+    ///
+    /// The section is clipped to the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Rect};
+    ///
+    /// let mut grid = Grid::new(NonZero::new(3).unwrap(), NonZero::new(3).unwrap(), || '.');
+    /// grid.fill_rect(Rect::from_corners((1, 1), (4, 4)), '#');
+    ///
+    /// assert_eq!(grid.to_string(), ".\t.\t.\n.\t#\t#\n.\t#\t#");
+    /// ```
+    pub fn fill_rect(&mut self, rect: Rect, value: T) {
+        self.enumerate_rect_mut(rect)
+            .for_each(|(_, item)| *item = value.clone());
+    }
+
+    /// Copies the given section into a new grid.
+    ///
+    /// The section is clipped to the grid.
+    /// Returns `None` if the section does not overlap with the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Rect};
+    ///
+    /// let grid = Grid::try_from((0u8..12, NonZero::new(4).unwrap())).unwrap();
+    /// let cropped = grid.crop(Rect::from_corners((1, 0), (2, 1))).unwrap();
+    ///
+    /// assert_eq!(cropped, Grid::try_from(([1, 2, 5, 6], NonZero::new(2).unwrap())).unwrap());
+    /// ```
+    #[must_use]
+    pub fn crop(&self, rect: Rect) -> Option<Self> {
+        self.view(rect).map(|view| view.to_grid())
+    }
+}
+
 impl<T> Grid<T>
 where
     T: PartialEq,
@@ -499,7 +972,7 @@ where
 /// ```
 impl<T, I> Index<I> for Grid<T>
 where
-    I: Into<Coordinate>,
+    I: TryInto<Coordinate>,
 {
     type Output = T;
 
@@ -510,7 +983,7 @@ where
 
 impl<T, I> IndexMut<I> for Grid<T>
 where
-    I: Into<Coordinate>,
+    I: TryInto<Coordinate>,
 {
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         self.get_mut(index).expect("index out of bounds")
@@ -567,3 +1040,11 @@ where
         Ok(unsafe { Self::new_unchecked(width, items) })
     }
 }
+
+/// Converts both end points of a line, if possible.
+fn end_points(
+    start: impl TryInto<Coordinate>,
+    end: impl TryInto<Coordinate>,
+) -> Option<(Coordinate, Coordinate)> {
+    Some((start.try_into().ok()?, end.try_into().ok()?))
+}
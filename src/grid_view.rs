@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::num::NonZero;
+
+use crate::{Coordinate, Grid, Rect};
+
+/// A read-only view of a rectangular section of a [`Grid`].
+///
+/// Coordinates passed to and returned by the view are relative to the view's upper left cell.
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct GridView<'grid, T> {
+    grid: &'grid Grid<T>,
+    rect: Rect,
+}
+
+impl<'grid, T> GridView<'grid, T> {
+    /// Creates a new view.
+    ///
+    /// The caller must ensure that `rect` lies entirely within `grid`.
+    pub(crate) const fn new(grid: &'grid Grid<T>, rect: Rect) -> Self {
+        Self { grid, rect }
+    }
+
+    /// Returns the section of the underlying grid that is visible through the view.
+    #[must_use]
+    pub const fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Returns the width of the view.
+    #[must_use]
+    pub const fn width(&self) -> NonZero<usize> {
+        self.rect.width()
+    }
+
+    /// Returns the height of the view.
+    #[must_use]
+    pub const fn height(&self) -> NonZero<usize> {
+        self.rect.height()
+    }
+
+    /// Returns an Option to a reference of the cell content at the given coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Rect};
+    ///
+    /// let grid = Grid::try_from((0u8..12, NonZero::new(4).unwrap())).unwrap();
+    /// let view = grid.view(Rect::from_corners((1, 1), (2, 2))).unwrap();
+    ///
+    /// assert_eq!(view.get((0, 0)), Some(&5));
+    /// assert_eq!(view.get((1, 1)), Some(&10));
+    /// assert_eq!(view.get((2, 0)), None);
+    /// ```
+    pub fn get(&self, coordinate: impl TryInto<Coordinate>) -> Option<&'grid T> {
+        let coordinate = coordinate.try_into().ok()?;
+
+        if coordinate.x() >= self.width().get() || coordinate.y() >= self.height().get() {
+            return None;
+        }
+
+        self.grid.get((
+            self.rect.origin().x() + coordinate.x(),
+            self.rect.origin().y() + coordinate.y(),
+        ))
+    }
+
+    /// Yields the rows of the view.
+    pub fn rows(&self) -> impl Iterator<Item = &'grid [T]> + use<'grid, T> {
+        let grid = self.grid;
+        let origin = self.rect.origin();
+        let width = self.width().get();
+        (origin.y()..origin.y() + self.height().get()).map(move |y| {
+            let start = y * grid.width().get() + origin.x();
+            &grid.as_ref()[start..start + width]
+        })
+    }
+
+    /// Yields references to the view's items in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'grid T> + use<'grid, T> {
+        self.rows().flatten()
+    }
+
+    /// Yields tuples of Coordinate and reference to the view's items.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Coordinate, &'grid T)> + use<'grid, T> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, item)| (Coordinate::new(x, y), item))
+        })
+    }
+}
+
+impl<T> GridView<'_, T>
+where
+    T: Clone,
+{
+    /// Copies the visible section into a new grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Rect};
+    ///
+    /// let grid = Grid::try_from((0u8..12, NonZero::new(4).unwrap())).unwrap();
+    /// let view = grid.view(Rect::from_corners((1, 1), (2, 2))).unwrap();
+    ///
+    /// assert_eq!(view.to_grid(), Grid::try_from(([5, 6, 9, 10], NonZero::new(2).unwrap())).unwrap());
+    /// ```
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T> {
+        let items: Vec<T> = self.iter().cloned().collect();
+        #[expect(unsafe_code)]
+        // SAFETY: The view yields exactly `width` items for each of its `height` rows.
+        unsafe {
+            Grid::new_unchecked(self.width(), items)
+        }
+    }
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Display for GridView<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for (x, element) in row.iter().enumerate() {
+                if x > 0 {
+                    write!(f, "\t")?;
+                }
+
+                write!(f, "{element}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
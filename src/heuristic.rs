@@ -0,0 +1,56 @@
+use crate::{Coordinate, Cost};
+
+/// An estimate of the remaining cost towards the goal of an A* search.
+///
+/// The estimate must never exceed the actual cost, or the search may not find the shortest path.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Heuristic<C> {
+    /// Estimates no cost at all, which turns A* into Dijkstra's algorithm.
+    Zero,
+    /// The Manhattan distance times the given cost of a single step, suitable for four-connected grids.
+    Manhattan(C),
+    /// The Chebyshev distance times the given cost of a single step,
+    /// suitable for eight-connected grids where diagonal steps cost the same as straight ones.
+    Chebyshev(C),
+    /// The octile distance, suitable for eight-connected grids with different costs for diagonal steps.
+    Octile {
+        /// The cost of a horizontal or vertical step.
+        straight: C,
+        /// The cost of a diagonal step.
+        diagonal: C,
+    },
+}
+
+impl<C> Heuristic<C>
+where
+    C: Cost,
+{
+    /// Estimates the cost of moving from `from` to `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Heuristic};
+    ///
+    /// let from = Coordinate::new(0, 0);
+    /// let to = Coordinate::new(4, 1);
+    ///
+    /// assert_eq!(Heuristic::<u32>::Zero.estimate(from, to), 0);
+    /// assert_eq!(Heuristic::Manhattan(1).estimate(from, to), 5);
+    /// assert_eq!(Heuristic::Chebyshev(1).estimate(from, to), 4);
+    /// assert_eq!(Heuristic::Octile { straight: 10, diagonal: 14 }.estimate(from, to), 44);
+    /// ```
+    #[must_use]
+    pub fn estimate(&self, from: Coordinate, to: Coordinate) -> C {
+        match *self {
+            Self::Zero => C::ZERO,
+            Self::Manhattan(step) => step.times(from.manhattan_distance(to)),
+            Self::Chebyshev(step) => step.times(from.chebyshev_distance(to)),
+            Self::Octile { straight, diagonal } => {
+                let dx = from.x().abs_diff(to.x());
+                let dy = from.y().abs_diff(to.y());
+                diagonal.times(dx.min(dy)) + straight.times(dx.abs_diff(dy))
+            }
+        }
+    }
+}
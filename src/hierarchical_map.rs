@@ -0,0 +1,519 @@
+use alloc::collections::{BTreeMap, BinaryHeap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::num::NonZero;
+
+use crate::{Connectivity, Coordinate, CoordinateSet, Grid, Path, Rect};
+
+/// Entrances of at least this width get a transition at each end instead of one in the middle.
+const WIDE_ENTRANCE: usize = 6;
+
+/// Marks cells that have not been reached by a local search.
+const UNREACHED: usize = usize::MAX;
+
+/// A precomputed abstraction of a grid for fast hierarchical pathfinding (HPA*).
+///
+/// The grid is divided into square clusters. Where the passable cells on both sides
+/// of a cluster border form an entrance, transition cells are placed as nodes of an abstract graph.
+/// Nodes of the same cluster are connected by the length of the shortest path between them
+/// within the cluster. Path queries search the much smaller abstract graph
+/// and refine the result into a path of cells.
+///
+/// Movement is four-connected with uniform cost.
+/// The resulting paths are close to, but not necessarily, the shortest ones.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZero;
+/// use grid2d::{Coordinate, Grid, HierarchicalMap};
+///
+/// let mut grid = Grid::new(NonZero::new(10).unwrap(), NonZero::new(10).unwrap(), || '.');
+///
+/// for y in 0..9 {
+///     grid[Coordinate::new(5, y)] = '#';
+/// }
+///
+/// let mut map = HierarchicalMap::new(&grid, NonZero::new(5).unwrap(), |cell| *cell == '.');
+/// let path = map.find_path((0, 0), (9, 0)).unwrap();
+///
+/// assert_eq!(path.cost(), 27);
+/// assert_eq!(path.steps().first(), Some(&Coordinate::new(0, 0)));
+/// assert_eq!(path.steps().last(), Some(&Coordinate::new(9, 0)));
+///
+/// map.update([((5, 9), false)]);
+/// assert!(map.find_path((0, 0), (9, 0)).is_none());
+///
+/// map.update([((5, 0), true)]);
+/// assert_eq!(map.find_path((0, 0), (9, 0)).unwrap().cost(), 9);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HierarchicalMap {
+    passable: CoordinateSet,
+    cluster_size: NonZero<usize>,
+    columns: usize,
+    rows: usize,
+    clusters: Vec<Vec<Node>>,
+}
+
+/// A transition cell in the abstract graph.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Node {
+    coordinate: Coordinate,
+    partners: Vec<Coordinate>,
+    edges: Vec<(Coordinate, usize)>,
+}
+
+impl HierarchicalMap {
+    /// Builds the abstraction of the grid with clusters of the given size.
+    ///
+    /// Cells matching the predicate are passable.
+    #[must_use]
+    pub fn new<T>(
+        grid: &Grid<T>,
+        cluster_size: NonZero<usize>,
+        passable: impl Fn(&T) -> bool,
+    ) -> Self {
+        let mut cells = CoordinateSet::for_grid(grid);
+        cells.extend(
+            grid.enumerate()
+                .filter(|(_, cell)| passable(cell))
+                .map(|(coordinate, _)| coordinate),
+        );
+        let columns = grid.width().get().div_ceil(cluster_size.get());
+        let rows = grid.height().get().div_ceil(cluster_size.get());
+        let mut map = Self {
+            passable: cells,
+            cluster_size,
+            columns,
+            rows,
+            clusters: vec![Vec::new(); columns * rows],
+        };
+
+        for cluster in 0..map.clusters.len() {
+            map.rebuild(cluster);
+        }
+
+        map
+    }
+
+    /// Returns the size of the clusters.
+    #[must_use]
+    pub const fn cluster_size(&self) -> NonZero<usize> {
+        self.cluster_size
+    }
+
+    /// Returns the amount of transition nodes in the abstract graph.
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.clusters.iter().map(Vec::len).sum()
+    }
+
+    /// Determines whether the given cell is passable.
+    pub fn is_passable(&self, coordinate: impl TryInto<Coordinate>) -> bool {
+        self.passable.contains(coordinate)
+    }
+
+    /// Changes the passability of cells and rebuilds the affected clusters.
+    ///
+    /// Only the clusters containing changed cells and their direct neighbors are rebuilt.
+    /// Coordinates outside the grid are ignored.
+    pub fn update<C>(&mut self, changes: impl IntoIterator<Item = (C, bool)>)
+    where
+        C: TryInto<Coordinate>,
+    {
+        let mut affected = vec![false; self.clusters.len()];
+
+        for (coordinate, passable) in changes {
+            let Ok(coordinate) = coordinate.try_into() else {
+                continue;
+            };
+
+            if !self.encompasses(coordinate) || self.passable.contains(coordinate) == passable {
+                continue;
+            }
+
+            if passable {
+                self.passable.insert(coordinate);
+            } else {
+                self.passable.remove(coordinate);
+            }
+
+            let (column, row) = self.cluster_position(coordinate);
+            affected[row * self.columns + column] = true;
+
+            for (column, row) in [
+                (column.wrapping_sub(1), row),
+                (column + 1, row),
+                (column, row.wrapping_sub(1)),
+                (column, row + 1),
+            ] {
+                if column < self.columns && row < self.rows {
+                    affected[row * self.columns + column] = true;
+                }
+            }
+        }
+
+        for (cluster, affected) in affected.into_iter().enumerate() {
+            if affected {
+                self.rebuild(cluster);
+            }
+        }
+    }
+
+    /// Finds a path between two passable cells.
+    ///
+    /// Returns `None` if either cell is impassable or not on the grid, or if no path exists.
+    #[must_use]
+    pub fn find_path(
+        &self,
+        start: impl TryInto<Coordinate>,
+        goal: impl TryInto<Coordinate>,
+    ) -> Option<Path<Coordinate, usize>> {
+        let start = start.try_into().ok()?;
+        let goal = goal.try_into().ok()?;
+
+        if !self.passable.contains(start) || !self.passable.contains(goal) {
+            return None;
+        }
+
+        let start_search = self.local_search(self.cluster_of(start), start);
+
+        if self.cluster_of(start) == self.cluster_of(goal)
+            && let Some(steps) = start_search.path_to(goal)
+        {
+            let cost = steps.len() - 1;
+            return Some(Path::new(steps, cost));
+        }
+
+        let goal_search = self.local_search(self.cluster_of(goal), goal);
+        let abstract_path = self.abstract_search(start, goal, &start_search, &goal_search)?;
+        let mut steps = vec![start];
+
+        for pair in abstract_path.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+
+            if from == to {
+                continue;
+            }
+
+            if self.cluster_of(from) == self.cluster_of(to) {
+                let segment = if from == start {
+                    start_search.path_to(to)
+                } else {
+                    self.local_search(self.cluster_of(from), from).path_to(to)
+                };
+                steps.extend(segment?.into_iter().skip(1));
+            } else {
+                steps.push(to);
+            }
+        }
+
+        let cost = steps.len() - 1;
+        Some(Path::new(steps, cost))
+    }
+
+    /// Searches the abstract graph from `start` to `goal`.
+    ///
+    /// Returns the sequence of start, transition nodes and goal.
+    fn abstract_search(
+        &self,
+        start: Coordinate,
+        goal: Coordinate,
+        start_search: &LocalSearch,
+        goal_search: &LocalSearch,
+    ) -> Option<Vec<Coordinate>> {
+        let width = self.passable.width();
+        let key = |coordinate: Coordinate| coordinate.as_index(width).unwrap_or(UNREACHED);
+        let mut best: BTreeMap<usize, (usize, Coordinate)> = BTreeMap::new();
+        let mut heap = BinaryHeap::new();
+        best.insert(key(start), (0, start));
+        heap.push(Reverse((start.manhattan_distance(goal), 0, key(start))));
+
+        while let Some(Reverse((_, cost, index))) = heap.pop() {
+            let coordinate = Coordinate::from_width_and_index(width, index);
+
+            if best.get(&index).is_some_and(|(known, _)| cost > *known) {
+                continue;
+            }
+
+            if coordinate == goal {
+                let mut path = vec![goal];
+                let mut current = goal;
+
+                while current != start {
+                    current = best[&key(current)].1;
+                    path.push(current);
+                }
+
+                path.reverse();
+                return Some(path);
+            }
+
+            let mut successors: Vec<(Coordinate, usize)> = Vec::new();
+
+            if coordinate == start {
+                successors.extend(self.clusters[self.cluster_of(start)].iter().filter_map(
+                    |node| {
+                        start_search
+                            .distance(node.coordinate)
+                            .map(|distance| (node.coordinate, distance))
+                    },
+                ));
+            }
+
+            if let Some(node) = self.node(coordinate) {
+                successors.extend(node.edges.iter().copied());
+                successors.extend(node.partners.iter().map(|partner| (*partner, 1)));
+            }
+
+            if self.cluster_of(coordinate) == self.cluster_of(goal)
+                && let Some(distance) = goal_search.distance(coordinate)
+            {
+                successors.push((goal, distance));
+            }
+
+            for (successor, distance) in successors {
+                let total = cost + distance;
+                let index = key(successor);
+
+                if best.get(&index).is_none_or(|(known, _)| total < *known) {
+                    best.insert(index, (total, coordinate));
+                    heap.push(Reverse((
+                        total + successor.manhattan_distance(goal),
+                        total,
+                        index,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Recomputes the transition nodes of a cluster and the edges between them.
+    fn rebuild(&mut self, cluster: usize) {
+        let (column, row) = (cluster % self.columns, cluster / self.columns);
+        let mut pairs: Vec<(Coordinate, Coordinate)> = Vec::new();
+
+        if column + 1 < self.columns {
+            pairs.extend(self.transitions(cluster, true));
+        }
+
+        if row + 1 < self.rows {
+            pairs.extend(self.transitions(cluster, false));
+        }
+
+        if column > 0 {
+            let transitions = self.transitions(cluster - 1, true);
+            pairs.extend(transitions.into_iter().map(|(own, other)| (other, own)));
+        }
+
+        if row > 0 {
+            let transitions = self.transitions(cluster - self.columns, false);
+            pairs.extend(transitions.into_iter().map(|(own, other)| (other, own)));
+        }
+
+        pairs.sort_unstable_by_key(|(own, other)| (own.y(), own.x(), other.y(), other.x()));
+        let mut nodes: Vec<Node> = Vec::new();
+
+        for (own, other) in pairs {
+            match nodes.last_mut() {
+                Some(node) if node.coordinate == own => node.partners.push(other),
+                _ => nodes.push(Node {
+                    coordinate: own,
+                    partners: vec![other],
+                    edges: Vec::new(),
+                }),
+            }
+        }
+
+        for index in 0..nodes.len() {
+            let search = self.local_search(cluster, nodes[index].coordinate);
+            nodes[index].edges = nodes
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .filter_map(|(_, other)| {
+                    search
+                        .distance(other.coordinate)
+                        .map(|distance| (other.coordinate, distance))
+                })
+                .collect();
+        }
+
+        self.clusters[cluster] = nodes;
+    }
+
+    /// Returns the transitions across the eastern or southern border of a cluster.
+    ///
+    /// Each transition is a pair of a cell within the cluster and the adjacent cell in the neighboring cluster.
+    fn transitions(&self, cluster: usize, east: bool) -> Vec<(Coordinate, Coordinate)> {
+        let rect = self.cluster_rect(cluster);
+        let (origin, corner) = (rect.origin(), rect.bottom_right());
+        let pair = |offset: usize| {
+            if east {
+                (
+                    Coordinate::new(corner.x(), origin.y() + offset),
+                    Coordinate::new(corner.x() + 1, origin.y() + offset),
+                )
+            } else {
+                (
+                    Coordinate::new(origin.x() + offset, corner.y()),
+                    Coordinate::new(origin.x() + offset, corner.y() + 1),
+                )
+            }
+        };
+        let length = if east { rect.height() } else { rect.width() }.get();
+        let open = |offset: usize| {
+            let (own, other) = pair(offset);
+            self.passable.contains(own) && self.passable.contains(other)
+        };
+        let mut transitions = Vec::new();
+        let mut offset = 0;
+
+        while offset < length {
+            if !open(offset) {
+                offset += 1;
+                continue;
+            }
+
+            let first = offset;
+
+            while offset < length && open(offset) {
+                offset += 1;
+            }
+
+            let last = offset - 1;
+
+            if offset - first < WIDE_ENTRANCE {
+                transitions.push(pair(first + (last - first) / 2));
+            } else {
+                transitions.push(pair(first));
+                transitions.push(pair(last));
+            }
+        }
+
+        transitions
+    }
+
+    /// Runs a breadth-first search from `start` that does not leave the given cluster.
+    fn local_search(&self, cluster: usize, start: Coordinate) -> LocalSearch {
+        let rect = self.cluster_rect(cluster);
+        let mut search = LocalSearch {
+            rect,
+            predecessors: vec![UNREACHED; rect.size().map_or(0, NonZero::get)],
+            distances: vec![UNREACHED; rect.size().map_or(0, NonZero::get)],
+        };
+        let Some(first) = search.index(start) else {
+            return search;
+        };
+
+        search.predecessors[first] = first;
+        search.distances[first] = 0;
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(coordinate) = queue.pop_front() {
+            let Some(index) = search.index(coordinate) else {
+                continue;
+            };
+
+            for neighbor in coordinate.neighbors_with(Connectivity::Four) {
+                if let Some(next) = search.index(neighbor)
+                    && search.distances[next] == UNREACHED
+                    && self.passable.contains(neighbor)
+                {
+                    search.predecessors[next] = index;
+                    search.distances[next] = search.distances[index] + 1;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        search
+    }
+
+    fn node(&self, coordinate: Coordinate) -> Option<&Node> {
+        let nodes = &self.clusters[self.cluster_of(coordinate)];
+        nodes
+            .binary_search_by_key(&(coordinate.y(), coordinate.x()), |node| {
+                (node.coordinate.y(), node.coordinate.x())
+            })
+            .ok()
+            .map(|index| &nodes[index])
+    }
+
+    const fn encompasses(&self, coordinate: Coordinate) -> bool {
+        coordinate.x() < self.passable.width().get()
+            && coordinate.y() < self.passable.height().get()
+    }
+
+    const fn cluster_position(&self, coordinate: Coordinate) -> (usize, usize) {
+        (
+            coordinate.x() / self.cluster_size.get(),
+            coordinate.y() / self.cluster_size.get(),
+        )
+    }
+
+    const fn cluster_of(&self, coordinate: Coordinate) -> usize {
+        let (column, row) = self.cluster_position(coordinate);
+        row * self.columns + column
+    }
+
+    fn cluster_rect(&self, cluster: usize) -> Rect {
+        let size = self.cluster_size.get();
+        let origin = Coordinate::new(cluster % self.columns * size, cluster / self.columns * size);
+        let clamp = |start: usize, total: NonZero<usize>| {
+            NonZero::new(size.min(total.get() - start)).unwrap_or(NonZero::<usize>::MIN)
+        };
+        Rect::new(
+            origin,
+            clamp(origin.x(), self.passable.width()),
+            clamp(origin.y(), self.passable.height()),
+        )
+    }
+}
+
+/// The result of a breadth-first search within a single cluster.
+#[derive(Clone, Debug)]
+struct LocalSearch {
+    rect: Rect,
+    predecessors: Vec<usize>,
+    distances: Vec<usize>,
+}
+
+impl LocalSearch {
+    fn index(&self, coordinate: Coordinate) -> Option<usize> {
+        let origin = self.rect.origin();
+        self.rect.contains(coordinate).then(|| {
+            (coordinate.y() - origin.y()) * self.rect.width().get() + coordinate.x() - origin.x()
+        })
+    }
+
+    fn distance(&self, coordinate: Coordinate) -> Option<usize> {
+        self.index(coordinate)
+            .map(|index| self.distances[index])
+            .filter(|distance| *distance != UNREACHED)
+    }
+
+    fn path_to(&self, goal: Coordinate) -> Option<Vec<Coordinate>> {
+        self.distance(goal)?;
+        let width = self.rect.width();
+        let origin = self.rect.origin();
+        let mut index = self.index(goal)?;
+        let mut steps = vec![goal];
+
+        while self.predecessors[index] != index {
+            index = self.predecessors[index];
+            let local = Coordinate::from_width_and_index(width, index);
+            steps.push(Coordinate::new(
+                origin.x() + local.x(),
+                origin.y() + local.y(),
+            ));
+        }
+
+        steps.reverse();
+        Some(steps)
+    }
+}
@@ -3,12 +3,74 @@
 #![deny(unsafe_code)]
 extern crate alloc;
 
+pub use self::arithmetic::{Column, Row};
 pub use self::builder::GridBuilder;
-pub use self::coordinate::Coordinate;
-pub use self::errors::{BuildError, CoordinateParseError, FromIterableError};
+pub use self::connectivity::Connectivity;
+pub use self::contour::Contour;
+pub use self::coordinate::{Coordinate, Coordinate16, Coordinate32, Notation};
+pub use self::coordinate_set::CoordinateSet;
+pub use self::corner::Corner;
+pub use self::cost::Cost;
+pub use self::direction::Direction;
+pub use self::edge_grid::EdgeGrid;
+pub use self::errors::{
+    BuildError, CoordinateParseError, DimensionMismatchError, DirectionParseError,
+    FromIterableError,
+};
+pub use self::field_of_view::Visibility;
 pub use self::grid::Grid;
+pub use self::grid_view::GridView;
+pub use self::heuristic::Heuristic;
+pub use self::hierarchical_map::HierarchicalMap;
+pub use self::line::{Bresenham, Supercover};
+pub use self::metric::Metric;
+pub use self::offset::Offset;
+pub use self::path::Path;
+pub use self::ray::{Ray, RayHit, RayTraversal};
+pub use self::rect::{Rect, RectCoordinates};
+pub use self::region::Region;
+pub use self::shape::Shape;
+pub use self::side::Side;
+pub use self::state::State;
+pub use self::symmetry::MirrorAxis;
+pub use self::transform::Transform;
+pub use self::transformed_view::TransformedView;
+pub use self::vertex_grid::VertexGrid;
 
+mod arithmetic;
+mod bisect;
 mod builder;
+mod cast;
+mod connectivity;
+mod contour;
 mod coordinate;
+mod coordinate_set;
+mod corner;
+mod cost;
+mod dijkstra_map;
+mod direction;
+mod distance_transform;
+mod edge_grid;
 mod errors;
+mod field_of_view;
+mod flood_fill;
 mod grid;
+mod grid_view;
+mod heuristic;
+mod hierarchical_map;
+mod line;
+mod metric;
+mod nearest;
+mod offset;
+mod path;
+mod pathfinding;
+mod ray;
+mod rect;
+mod region;
+mod shape;
+mod side;
+mod state;
+mod symmetry;
+mod transform;
+mod transformed_view;
+mod vertex_grid;
@@ -0,0 +1,412 @@
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+use crate::bisect::partition_point;
+use crate::cast::{to_i128, to_u128};
+use crate::{Coordinate, Rect};
+
+/// Yields the cells on the straight line between two coordinates using Bresenham's algorithm.
+///
+/// Both end points are included.
+/// Every step advances to one of the eight neighbors of the current cell,
+/// so diagonal lines may pass between cells that touch the exact line.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::{Bresenham, Coordinate};
+///
+/// let line: Vec<_> = Bresenham::new((0, 0), (4, 2)).map(<(usize, usize)>::from).collect();
+///
+/// assert_eq!(line, [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]);
+/// assert_eq!(Bresenham::new((3, 3), (3, 3)).collect::<Vec<_>>(), [Coordinate::new(3, 3)]);
+/// assert_eq!(Bresenham::new((5, 1), (0, 3)).len(), 6);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Bresenham {
+    current: Coordinate,
+    end: Coordinate,
+    dx: i128,
+    dy: i128,
+    error: i128,
+    forwards: (bool, bool),
+    remaining: usize,
+}
+
+impl Bresenham {
+    /// Creates a new line from `start` to `end`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an end point cannot be converted into a [`Coordinate`].
+    pub fn new(start: impl TryInto<Coordinate>, end: impl TryInto<Coordinate>) -> Self {
+        let (Ok(start), Ok(end)) = (start.try_into(), end.try_into()) else {
+            panic!("coordinate out of range");
+        };
+        let dx = to_i128(start.x().abs_diff(end.x()));
+        let dy = -to_i128(start.y().abs_diff(end.y()));
+        Self {
+            current: start,
+            end,
+            dx,
+            dy,
+            error: dx + dy,
+            forwards: (start.x() < end.x(), start.y() < end.y()),
+            remaining: start.chebyshev_distance(end).saturating_add(1),
+        }
+    }
+
+    /// Creates a new line from `start` to `end` that only yields the cells within `rect`.
+    ///
+    /// The first cell within the rectangle is found by bisecting the steps along the line,
+    /// so the cells outside of the rectangle are never walked.
+    pub(crate) fn clipped(start: Coordinate, end: Coordinate, rect: &Rect) -> Self {
+        let mut line = Self::new(start, end);
+        let (lower, upper) = (rect.origin(), rect.bottom_right());
+        let length = to_u128(start.chebyshev_distance(end)) + 1;
+        let position = |steps: u128| {
+            let (x, y, _) = line.after(steps);
+            let x = offset(start.x(), x, line.forwards.0);
+            let y = offset(start.y(), y, line.forwards.1);
+            (x, y)
+        };
+        let before = |(x, y): (usize, usize)| {
+            (if line.forwards.0 {
+                x < lower.x()
+            } else {
+                x > upper.x()
+            }) || (if line.forwards.1 {
+                y < lower.y()
+            } else {
+                y > upper.y()
+            })
+        };
+        let after = |(x, y): (usize, usize)| {
+            (if line.forwards.0 {
+                x > upper.x()
+            } else {
+                x < lower.x()
+            }) || (if line.forwards.1 {
+                y > upper.y()
+            } else {
+                y < lower.y()
+            })
+        };
+        let first = partition_point(length, |steps| before(position(steps)));
+        let last = partition_point(length, |steps| !after(position(steps)));
+
+        if first >= last {
+            line.remaining = 0;
+            return line;
+        }
+
+        let (x, y, error) = line.after(first);
+        line.current = Coordinate::new(
+            offset(start.x(), x, line.forwards.0),
+            offset(start.y(), y, line.forwards.1),
+        );
+        line.error = error;
+        line.remaining = usize::try_from(last - first).unwrap_or(usize::MAX);
+        line
+    }
+
+    /// Returns the horizontal and vertical steps taken and the error after the given number of iterations.
+    ///
+    /// The minor component advances whenever the exact line has passed the middle between two cells,
+    /// which is `floor(minor * steps / major + 1 / 2)`, computed without overflowing.
+    fn after(&self, steps: u128) -> (u128, u128, i128) {
+        let (dx, dy) = (self.dx.unsigned_abs(), self.dy.unsigned_abs());
+
+        if dx == 0 && dy == 0 {
+            return (0, 0, 0);
+        }
+
+        let (major, minor) = (dx.max(dy), dx.min(dy));
+        let (quotient, remainder) = (minor * steps / major, minor * steps % major);
+        let round_up = 2 * remainder >= major;
+        let minor_steps = quotient + u128::from(round_up);
+        let remainder = remainder.cast_signed();
+        let major = major.cast_signed();
+
+        if dx >= dy {
+            let error = self.dx + self.dy - remainder + if round_up { major } else { 0 };
+            (steps, minor_steps, error)
+        } else {
+            let error = self.dx + self.dy + remainder - if round_up { major } else { 0 };
+            (minor_steps, steps, error)
+        }
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = Coordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let coordinate = self.current;
+        self.remaining -= 1;
+
+        if coordinate == self.end {
+            self.remaining = 0;
+            return Some(coordinate);
+        }
+
+        let double_error = 2 * self.error;
+        let (mut x, mut y) = coordinate.into();
+
+        if double_error >= self.dy {
+            self.error += self.dy;
+            x = step(x, self.forwards.0);
+        }
+
+        if double_error <= self.dx {
+            self.error += self.dx;
+            y = step(y, self.forwards.1);
+        }
+
+        self.current = Coordinate::new(x, y);
+        Some(coordinate)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Bresenham {}
+
+impl FusedIterator for Bresenham {}
+
+/// Yields every cell that the straight line between the centers of two cells touches.
+///
+/// Both end points are included.
+/// Every step advances to one of the four orthogonal neighbors of the current cell,
+/// except where the line passes exactly through the corner of a cell:
+/// there, the horizontal and then the vertical neighbor adjacent to that corner are yielded,
+/// which are diagonal to each other, followed by the diagonal neighbor of the current cell.
+/// Use [`Grid::supercover`](crate::Grid::supercover) to only walk the cells on a grid.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::Supercover;
+///
+/// let line: Vec<_> = Supercover::new((0, 0), (3, 1)).map(<(usize, usize)>::from).collect();
+/// assert_eq!(line, [(0, 0), (1, 0), (2, 0), (1, 1), (2, 1), (3, 1)]);
+///
+/// let diagonal: Vec<_> = Supercover::new((2, 2), (0, 0)).map(<(usize, usize)>::from).collect();
+/// assert_eq!(diagonal, [(2, 2), (1, 2), (2, 1), (1, 1), (0, 1), (1, 0), (0, 0)]);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Supercover {
+    position: Coordinate,
+    queue: [Option<Coordinate>; 2],
+    steps: (u128, u128),
+    taken: (u128, u128),
+    error: i128,
+    forwards: (bool, bool),
+    lower: Coordinate,
+    upper: Coordinate,
+}
+
+impl Supercover {
+    /// Creates a new line from `start` to `end`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an end point cannot be converted into a [`Coordinate`].
+    pub fn new(start: impl TryInto<Coordinate>, end: impl TryInto<Coordinate>) -> Self {
+        let (Ok(start), Ok(end)) = (start.try_into(), end.try_into()) else {
+            panic!("coordinate out of range");
+        };
+        let steps = (
+            to_u128(start.x().abs_diff(end.x())),
+            to_u128(start.y().abs_diff(end.y())),
+        );
+        Self {
+            position: start,
+            queue: [Some(start), None],
+            steps,
+            taken: (0, 0),
+            error: steps.1.cast_signed() - steps.0.cast_signed(),
+            forwards: (start.x() < end.x(), start.y() < end.y()),
+            lower: Coordinate::new(start.x().min(end.x()), start.y().min(end.y())),
+            upper: Coordinate::new(start.x().max(end.x()), start.y().max(end.y())),
+        }
+    }
+
+    /// Creates a new line from `start` to `end` that only yields the cells within `rect`.
+    ///
+    /// The line starts right before the first cell within the rectangle,
+    /// which is computed in closed form, so the cells outside of the rectangle are never walked.
+    pub(crate) fn clipped(start: Coordinate, end: Coordinate, rect: &Rect) -> Self {
+        let mut line = Self::new(start, end);
+        line.lower = rect.origin();
+        line.upper = rect.bottom_right();
+        let (nx, ny) = line.steps;
+        let missing = |value: usize, lower: usize, upper: usize, forwards: bool| {
+            to_u128(if forwards {
+                lower.saturating_sub(value)
+            } else {
+                value.saturating_sub(upper)
+            })
+        };
+        let columns = missing(start.x(), line.lower.x(), line.upper.x(), line.forwards.0);
+        let rows = missing(start.y(), line.lower.y(), line.upper.y(), line.forwards.1);
+
+        if columns > nx || rows > ny {
+            line.queue = [None, None];
+            line.taken = line.steps;
+            return line;
+        }
+
+        let by_column = columns
+            .checked_sub(1)
+            .map(|column| (column, last_in_column(column, nx, ny)));
+        let by_row = rows
+            .checked_sub(1)
+            .map(|row| (last_in_column(row, ny, nx), row));
+        let (ix, iy) = match (by_column, by_row) {
+            (Some(column), Some(row)) if row.0 + row.1 > column.0 + column.1 => row,
+            (Some(last), _) | (None, Some(last)) => last,
+            (None, None) => return line,
+        };
+        let (x, y) = (
+            offset(start.x(), ix, line.forwards.0),
+            offset(start.y(), iy, line.forwards.1),
+        );
+
+        line.position = Coordinate::new(x, y);
+        line.queue = [None, None];
+        line.taken = (ix, iy);
+        line.error = error_at(ix, iy, nx, ny);
+        line
+    }
+
+    /// Determines whether the cell lies beyond the bounds in the direction of the line.
+    const fn is_past(&self, coordinate: Coordinate) -> bool {
+        (if self.forwards.0 {
+            coordinate.x() > self.upper.x()
+        } else {
+            coordinate.x() < self.lower.x()
+        }) || (if self.forwards.1 {
+            coordinate.y() > self.upper.y()
+        } else {
+            coordinate.y() < self.lower.y()
+        })
+    }
+
+    /// Determines whether the cell lies within the bounds.
+    fn is_within(&self, coordinate: Coordinate) -> bool {
+        (self.lower.x()..=self.upper.x()).contains(&coordinate.x())
+            && (self.lower.y()..=self.upper.y()).contains(&coordinate.y())
+    }
+
+    /// Advances the position along the line and returns the next cell.
+    ///
+    /// When the line passes through a corner, the remaining cells are queued.
+    ///
+    /// The error is `(1 + 2 * ix) * ny - (1 + 2 * iy) * nx` for `ix` horizontal and `iy` vertical steps taken
+    /// out of `nx` and `ny`. Its sign tells which cell border the line crosses next.
+    /// It is updated incrementally, since the products themselves may overflow.
+    fn advance(&mut self) -> Option<Coordinate> {
+        let (nx, ny) = self.steps;
+        let (ix, iy) = self.taken;
+
+        if (ix == nx && iy == ny) || self.is_past(self.position) {
+            return None;
+        }
+        let (x, y) = self.position.into();
+        let horizontal = || Coordinate::new(step(x, self.forwards.0), y);
+        let vertical = || Coordinate::new(x, step(y, self.forwards.1));
+
+        match self.error.cmp(&0) {
+            Ordering::Less => {
+                self.taken.0 += 1;
+                self.error += 2 * ny.cast_signed();
+                self.position = horizontal();
+            }
+            Ordering::Greater => {
+                self.taken.1 += 1;
+                self.error -= 2 * nx.cast_signed();
+                self.position = vertical();
+            }
+            Ordering::Equal => {
+                self.taken = (ix + 1, iy + 1);
+                self.error += 2 * ny.cast_signed() - 2 * nx.cast_signed();
+                let (horizontal, vertical) = (horizontal(), vertical());
+                self.position = Coordinate::new(horizontal.x(), vertical.y());
+                self.queue = [Some(vertical), Some(self.position)];
+                return Some(horizontal);
+            }
+        }
+
+        Some(self.position)
+    }
+}
+
+impl Iterator for Supercover {
+    type Item = Coordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let coordinate = self
+                .queue
+                .iter_mut()
+                .find_map(Option::take)
+                .or_else(|| self.advance())?;
+
+            if self.is_within(coordinate) {
+                return Some(coordinate);
+            }
+        }
+    }
+}
+
+impl FusedIterator for Supercover {}
+
+/// Moves a component the given number of steps forwards or backwards.
+fn offset(value: usize, steps: u128, forwards: bool) -> usize {
+    let steps = usize::try_from(steps).unwrap_or(usize::MAX);
+
+    if forwards {
+        value.saturating_add(steps)
+    } else {
+        value.saturating_sub(steps)
+    }
+}
+
+/// Returns the vertical steps taken at the last cell of the supercover in the given column,
+/// for a line of `nx` horizontal and `ny` vertical steps with `column < nx`.
+///
+/// The line leaves the column at `(2 * column + 1) * ny / (2 * nx)`, which is split into
+/// the quotient and remainder of `column * ny / nx`, since the products may overflow.
+fn last_in_column(column: u128, nx: u128, ny: u128) -> u128 {
+    let (quotient, remainder) = (column * ny / nx, column * ny % nx);
+    let numerator = 2 * remainder.cast_signed() + ny.cast_signed() - nx.cast_signed();
+    let denominator = 2 * nx.cast_signed();
+    let rounded =
+        numerator.div_euclid(denominator) + i128::from(numerator.rem_euclid(denominator) != 0);
+    quotient + rounded.cast_unsigned()
+}
+
+/// Returns the error of a supercover after `ix` horizontal and `iy` vertical steps,
+/// which must lie on the line.
+const fn error_at(ix: u128, iy: u128, nx: u128, ny: u128) -> i128 {
+    if nx == 0 {
+        return ny.cast_signed();
+    }
+
+    let (quotient, remainder) = (ix * ny / nx, ix * ny % nx);
+    ny.cast_signed() + 2 * remainder.cast_signed()
+        - nx.cast_signed()
+        - 2 * nx.cast_signed() * (iy.cast_signed() - quotient.cast_signed())
+}
+
+/// Moves a component one step forwards or backwards.
+const fn step(value: usize, forwards: bool) -> usize {
+    if forwards { value + 1 } else { value - 1 }
+}
@@ -0,0 +1,46 @@
+use crate::Coordinate;
+
+/// Measures the distance between two coordinates.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Metric {
+    /// The sum of the horizontal and vertical distance.
+    Manhattan,
+    /// The maximum of the horizontal and vertical distance.
+    Chebyshev,
+    /// The straight-line distance, which is measured squared to keep it exact.
+    Euclidean,
+}
+
+impl Metric {
+    /// Returns the distance between the coordinates, saturating at `usize::MAX`.
+    ///
+    /// The [`Metric::Euclidean`] distance is squared.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if a coordinate cannot be converted into a [`Coordinate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Metric};
+    ///
+    /// let (from, to) = (Coordinate::new(1, 2), Coordinate::new(4, 6));
+    ///
+    /// assert_eq!(Metric::Manhattan.distance(from, to), 7);
+    /// assert_eq!(Metric::Chebyshev.distance(from, to), 4);
+    /// assert_eq!(Metric::Euclidean.distance(from, to), 25);
+    /// ```
+    #[must_use]
+    pub fn distance(self, from: impl TryInto<Coordinate>, to: impl TryInto<Coordinate>) -> usize {
+        let (Ok(from), Ok(to)) = (from.try_into(), to.try_into()) else {
+            panic!("coordinate out of range");
+        };
+
+        match self {
+            Self::Manhattan => from.manhattan_distance(to),
+            Self::Chebyshev => from.chebyshev_distance(to),
+            Self::Euclidean => from.squared_euclidean_distance(to),
+        }
+    }
+}
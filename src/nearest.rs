@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+
+use crate::{Coordinate, Grid, Metric};
+
+impl<T> Grid<T> {
+    /// Returns the cells at the given radius around the center in row-major order.
+    ///
+    /// The ring is a diamond for [`Metric::Manhattan`] and a square otherwise.
+    /// Cells outside the grid are skipped. The ring of radius zero is the center itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Metric};
+    ///
+    /// let grid = Grid::try_from(("abcdefghijklmnopqrstuvwxy".chars(), NonZero::new(5).unwrap())).unwrap();
+    /// let ring = |radius, metric| {
+    ///     grid.ring(Coordinate::new(1, 1), radius, metric)
+    ///         .map(|(_, cell)| *cell)
+    ///         .collect::<String>()
+    /// };
+    ///
+    /// assert_eq!(ring(0, Metric::Chebyshev), "g");
+    /// assert_eq!(ring(1, Metric::Chebyshev), "abcfhklm");
+    /// assert_eq!(ring(2, Metric::Chebyshev), "dinpqrs");
+    /// assert_eq!(ring(2, Metric::Manhattan), "acikmq");
+    /// ```
+    pub fn ring(
+        &self,
+        center: impl TryInto<Coordinate>,
+        radius: usize,
+        metric: Metric,
+    ) -> impl Iterator<Item = (Coordinate, &T)> {
+        center
+            .try_into()
+            .into_iter()
+            .flat_map(move |center| self.ring_around(center, radius, metric))
+    }
+
+    /// Returns the rings of growing radius around the center, until they leave the grid.
+    ///
+    /// See [`Grid::ring`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Metric};
+    ///
+    /// let grid = Grid::try_from(("abcdefghi".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let rings: Vec<String> = grid
+    ///     .rings(Coordinate::new(0, 0), Metric::Manhattan)
+    ///     .map(|ring| ring.into_iter().map(|(_, cell)| *cell).collect())
+    ///     .collect();
+    ///
+    /// assert_eq!(rings, ["a", "bd", "ceg", "fh", "i"]);
+    /// ```
+    pub fn rings(
+        &self,
+        center: impl TryInto<Coordinate>,
+        metric: Metric,
+    ) -> impl Iterator<Item = Vec<(Coordinate, &T)>> {
+        center.try_into().into_iter().flat_map(move |center| {
+            (0..=self.outermost_ring(center, metric))
+                .map(move |radius| self.ring_around(center, radius, metric).collect())
+        })
+    }
+
+    /// Returns the matching cells closest to the given coordinate in row-major order.
+    ///
+    /// Searches outward in rings, so that the cost depends on the distance to the closest match,
+    /// rather than the size of the grid. Returns all matches of the same distance, or none,
+    /// if no cell matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Metric};
+    ///
+    /// let grid = Grid::try_from(("...x......x..x.x".chars(), NonZero::new(4).unwrap())).unwrap();
+    /// let nearest = |metric| -> Vec<(usize, usize)> {
+    ///     grid.nearest(Coordinate::new(0, 1), |cell| *cell == 'x', metric)
+    ///         .into_iter()
+    ///         .map(|(coordinate, _)| coordinate.into())
+    ///         .collect()
+    /// };
+    ///
+    /// assert_eq!(nearest(Metric::Manhattan), [(2, 2), (1, 3)]);
+    /// assert_eq!(nearest(Metric::Chebyshev), [(2, 2), (1, 3)]);
+    /// assert_eq!(nearest(Metric::Euclidean), [(2, 2), (1, 3)]);
+    /// ```
+    pub fn nearest(
+        &self,
+        from: impl TryInto<Coordinate>,
+        predicate: impl Fn(&T) -> bool,
+        metric: Metric,
+    ) -> Vec<(Coordinate, &T)> {
+        let Ok(from) = from.try_into() else {
+            return Vec::new();
+        };
+        let mut best = usize::MAX;
+        let mut nearest = Vec::new();
+
+        for radius in 0..=self.outermost_ring(from, metric) {
+            let closest = match metric {
+                Metric::Manhattan | Metric::Chebyshev => radius,
+                Metric::Euclidean => radius.saturating_mul(radius),
+            };
+
+            if closest > best {
+                break;
+            }
+
+            for (coordinate, cell) in self.ring_around(from, radius, metric) {
+                if !predicate(cell) {
+                    continue;
+                }
+
+                let distance = metric.distance(from, coordinate);
+
+                if distance < best {
+                    best = distance;
+                    nearest.clear();
+                }
+
+                if distance == best {
+                    nearest.push((coordinate, cell));
+                }
+            }
+        }
+
+        nearest.sort_unstable_by_key(|(coordinate, _)| (coordinate.y(), coordinate.x()));
+        nearest
+    }
+
+    /// Returns the cells at the given radius around the center, see [`Grid::ring`].
+    fn ring_around(
+        &self,
+        center: Coordinate,
+        radius: usize,
+        metric: Metric,
+    ) -> impl Iterator<Item = (Coordinate, &T)> {
+        let last = center.y().saturating_add(radius);
+
+        (center.y().saturating_sub(radius)..=last.min(self.height().get() - 1)).flat_map(move |y| {
+            let dy = y.abs_diff(center.y());
+            let (half_width, full) = match metric {
+                Metric::Manhattan => (radius - dy, false),
+                Metric::Chebyshev | Metric::Euclidean => (radius, dy == radius),
+            };
+            let left = center.x().checked_sub(half_width);
+            let right = center.x().checked_add(half_width);
+            let span = full.then(|| left.unwrap_or(0)..=right.unwrap_or(usize::MAX));
+            let ends = (!full).then_some([left, right.filter(|_| half_width > 0)]);
+
+            span.into_iter()
+                .flatten()
+                .take_while(|x| *x < self.width().get())
+                .chain(ends.into_iter().flatten().flatten())
+                .filter_map(move |x| {
+                    let coordinate = Coordinate::new(x, y);
+                    self.get(coordinate).map(|cell| (coordinate, cell))
+                })
+        })
+    }
+
+    /// Returns the radius of the outermost ring around the center that may intersect the grid.
+    fn outermost_ring(&self, center: Coordinate, metric: Metric) -> usize {
+        let (right, bottom) = (self.width().get() - 1, self.height().get() - 1);
+        let shape = match metric {
+            Metric::Manhattan => Metric::Manhattan,
+            Metric::Chebyshev | Metric::Euclidean => Metric::Chebyshev,
+        };
+
+        [(0, 0), (right, 0), (0, bottom), (right, bottom)]
+            .into_iter()
+            .map(|corner| shape.distance(center, corner))
+            .max()
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,301 @@
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Direction, Side};
+
+/// Signed displacement between two cells on a two-dimensional grid.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Offset {
+    dx: isize,
+    dy: isize,
+}
+
+impl Offset {
+    /// The offset that does not move at all.
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// Creates a new offset.
+    #[must_use]
+    pub const fn new(dx: isize, dy: isize) -> Self {
+        Self { dx, dy }
+    }
+
+    /// Returns the x component
+    #[must_use]
+    pub const fn dx(&self) -> isize {
+        self.dx
+    }
+
+    /// Returns the y component
+    #[must_use]
+    pub const fn dy(&self) -> isize {
+        self.dy
+    }
+
+    /// Returns the sum of the absolute components, saturating at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Offset;
+    ///
+    /// assert_eq!(Offset::new(-3, 4).manhattan_length(), 7);
+    /// ```
+    #[must_use]
+    pub const fn manhattan_length(&self) -> usize {
+        self.dx
+            .unsigned_abs()
+            .saturating_add(self.dy.unsigned_abs())
+    }
+
+    /// Returns the larger one of the absolute components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Offset;
+    ///
+    /// assert_eq!(Offset::new(-3, 4).chebyshev_length(), 4);
+    /// ```
+    #[must_use]
+    pub const fn chebyshev_length(&self) -> usize {
+        let dx = self.dx.unsigned_abs();
+        let dy = self.dy.unsigned_abs();
+
+        if dx > dy { dx } else { dy }
+    }
+
+    /// Returns the squared euclidean length, saturating at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Offset;
+    ///
+    /// assert_eq!(Offset::new(-3, 4).squared_euclidean_length(), 25);
+    /// ```
+    #[must_use]
+    pub const fn squared_euclidean_length(&self) -> usize {
+        let dx = self.dx.unsigned_abs();
+        let dy = self.dy.unsigned_abs();
+        dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy))
+    }
+
+    /// Adds two offsets, returning `None` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Offset;
+    ///
+    /// assert_eq!(Offset::new(1, -2).checked_add(Offset::new(3, 4)), Some(Offset::new(4, 2)));
+    /// assert_eq!(Offset::new(isize::MAX, 0).checked_add(Offset::new(1, 0)), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match (self.dx.checked_add(other.dx), self.dy.checked_add(other.dy)) {
+            (Some(dx), Some(dy)) => Some(Self::new(dx, dy)),
+            _ => None,
+        }
+    }
+
+    /// Subtracts two offsets, returning `None` on overflow.
+    #[must_use]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match (self.dx.checked_sub(other.dx), self.dy.checked_sub(other.dy)) {
+            (Some(dx), Some(dy)) => Some(Self::new(dx, dy)),
+            _ => None,
+        }
+    }
+
+    /// Multiplies the offset by a scalar, returning `None` on overflow.
+    #[must_use]
+    pub const fn checked_mul(self, factor: isize) -> Option<Self> {
+        match (self.dx.checked_mul(factor), self.dy.checked_mul(factor)) {
+            (Some(dx), Some(dy)) => Some(Self::new(dx, dy)),
+            _ => None,
+        }
+    }
+
+    /// Negates the offset, returning `None` if a component is `isize::MIN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Offset;
+    ///
+    /// assert_eq!(Offset::new(1, -2).checked_neg(), Some(Offset::new(-1, 2)));
+    /// assert_eq!(Offset::new(isize::MIN, 0).checked_neg(), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match (self.dx.checked_neg(), self.dy.checked_neg()) {
+            (Some(dx), Some(dy)) => Some(Self::new(dx, dy)),
+            _ => None,
+        }
+    }
+
+    /// Adds two offsets, clamping each component to the range of `isize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Offset;
+    ///
+    /// assert_eq!(
+    ///     Offset::new(isize::MAX, 1).saturating_add(Offset::new(1, 1)),
+    ///     Offset::new(isize::MAX, 2)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self::new(
+            self.dx.saturating_add(other.dx),
+            self.dy.saturating_add(other.dy),
+        )
+    }
+
+    /// Subtracts two offsets, clamping each component to the range of `isize`.
+    #[must_use]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self::new(
+            self.dx.saturating_sub(other.dx),
+            self.dy.saturating_sub(other.dy),
+        )
+    }
+
+    /// Multiplies the offset by a scalar, clamping each component to the range of `isize`.
+    #[must_use]
+    pub const fn saturating_mul(self, factor: isize) -> Self {
+        Self::new(
+            self.dx.saturating_mul(factor),
+            self.dy.saturating_mul(factor),
+        )
+    }
+
+    /// Negates the offset, mapping `isize::MIN` to `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Offset;
+    ///
+    /// assert_eq!(Offset::new(isize::MIN, 3).saturating_neg(), Offset::new(isize::MAX, -3));
+    /// ```
+    #[must_use]
+    pub const fn saturating_neg(self) -> Self {
+        Self::new(self.dx.saturating_neg(), self.dy.saturating_neg())
+    }
+}
+
+/// Add two offsets.
+///
+/// # Panics
+///
+/// Panics if a component overflows.
+/// Use [`Offset::checked_add`] or [`Offset::saturating_add`] instead.
+impl Add for Offset {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("offset overflow")
+    }
+}
+
+impl AddAssign for Offset {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtract two offsets.
+///
+/// # Panics
+///
+/// Panics if a component overflows.
+/// Use [`Offset::checked_sub`] or [`Offset::saturating_sub`] instead.
+impl Sub for Offset {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("offset overflow")
+    }
+}
+
+impl SubAssign for Offset {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Scale an offset.
+///
+/// # Panics
+///
+/// Panics if a component overflows.
+/// Use [`Offset::checked_mul`] or [`Offset::saturating_mul`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use grid2d::{Direction, Offset};
+///
+/// assert_eq!(Offset::from(Direction::NorthEast) * 3, Offset::new(3, -3));
+/// ```
+impl Mul<isize> for Offset {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        self.checked_mul(rhs).expect("offset overflow")
+    }
+}
+
+impl MulAssign<isize> for Offset {
+    fn mul_assign(&mut self, rhs: isize) {
+        *self = *self * rhs;
+    }
+}
+
+/// Negate an offset.
+///
+/// # Panics
+///
+/// Panics if a component is `isize::MIN`.
+/// Use [`Offset::checked_neg`] or [`Offset::saturating_neg`] instead.
+impl Neg for Offset {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("offset overflow")
+    }
+}
+
+impl Display for Offset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.dx, self.dy)
+    }
+}
+
+impl From<(isize, isize)> for Offset {
+    fn from((dx, dy): (isize, isize)) -> Self {
+        Self::new(dx, dy)
+    }
+}
+
+impl From<Offset> for (isize, isize) {
+    fn from(offset: Offset) -> Self {
+        (offset.dx, offset.dy)
+    }
+}
+
+impl From<Direction> for Offset {
+    fn from(direction: Direction) -> Self {
+        direction.offset().into()
+    }
+}
+
+impl From<Side> for Offset {
+    fn from(side: Side) -> Self {
+        side.offset().into()
+    }
+}
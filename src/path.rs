@@ -0,0 +1,37 @@
+use alloc::vec::Vec;
+
+/// A path found by a search, along with its total cost.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Path<N, C> {
+    steps: Vec<N>,
+    cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    pub(crate) const fn new(steps: Vec<N>, cost: C) -> Self {
+        Self { steps, cost }
+    }
+
+    /// Returns the steps of the path, including its start and its end.
+    #[must_use]
+    pub fn steps(&self) -> &[N] {
+        &self.steps
+    }
+
+    /// Consumes the path and returns its steps.
+    #[must_use]
+    pub fn into_steps(self) -> Vec<N> {
+        self.steps
+    }
+}
+
+impl<N, C> Path<N, C>
+where
+    C: Copy,
+{
+    /// Returns the total cost of the path.
+    #[must_use]
+    pub const fn cost(&self) -> C {
+        self.cost
+    }
+}
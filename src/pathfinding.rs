@@ -0,0 +1,545 @@
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{Ordering, Reverse};
+
+use crate::{Connectivity, Coordinate, Cost, Grid, Heuristic, Path, State};
+
+/// Marks cells that have not been reached by a search.
+const UNREACHED: usize = usize::MAX;
+
+impl<T> Grid<T> {
+    /// Finds a path with the fewest steps from `start` to `goal` using breadth-first search.
+    ///
+    /// Only cells matching the predicate can be entered. The start cell is not checked.
+    /// Returns `None` if either coordinate is not on the grid or the goal is unreachable.
+    /// Coordinates of any component type are accepted, like by [`Grid::get`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Coordinate, Coordinate32, Grid};
+    ///
+    /// let grid = Grid::try_from(("...#..#.#..#...".chars(), NonZero::new(5).unwrap())).unwrap();
+    /// let path = grid.bfs((0, 0), (4, 0), Connectivity::Four, |cell| *cell != '#').unwrap();
+    ///
+    /// assert_eq!(path.cost(), 8);
+    /// assert_eq!(path.steps()[4], Coordinate::new(2, 2));
+    /// assert!(grid.bfs((0, 0), (3, 0), Connectivity::Four, |cell| *cell != '#').is_none());
+    ///
+    /// let (start, goal) = (Coordinate32::from((0, 0)), Coordinate32::from((4, 0)));
+    /// assert_eq!(grid.bfs(start, goal, Connectivity::Four, |cell| *cell != '#'), Some(path));
+    /// ```
+    pub fn bfs(
+        &self,
+        start: impl TryInto<Coordinate>,
+        goal: impl TryInto<Coordinate>,
+        connectivity: Connectivity,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Path<Coordinate, usize>> {
+        let start = self.index_of(start)?;
+        let goal = self.index_of(goal)?;
+        let cells: &[T] = self;
+        let mut predecessors = vec![UNREACHED; cells.len()];
+        predecessors[start] = start;
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(index) = queue.pop_front() {
+            if index == goal {
+                let steps = self.trace(&predecessors, goal);
+                let cost = steps.len() - 1;
+                return Some(Path::new(steps, cost));
+            }
+
+            let coordinate = Coordinate::from_width_and_index(self.width(), index);
+
+            for neighbor in coordinate.neighbors_with(connectivity) {
+                if let Some(next) = self.index_of(neighbor)
+                    && predecessors[next] == UNREACHED
+                    && passable(&cells[next])
+                {
+                    predecessors[next] = index;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Finds a cheapest path from `start` to `goal` using Dijkstra's algorithm.
+    ///
+    /// The cost function receives the value of the cell that is entered,
+    /// as well as the coordinates that are moved from and to.
+    /// It returns `None` if the move is impossible.
+    /// Returns `None` if either coordinate is not on the grid or the goal is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::try_from(([1, 9, 1, 1, 9, 1, 1, 1, 1], NonZero::new(3).unwrap())).unwrap();
+    /// let path = grid.dijkstra((0, 0), (2, 0), Connectivity::Four, |cost, _, _| Some(*cost)).unwrap();
+    ///
+    /// assert_eq!(path.cost(), 6);
+    /// assert_eq!(path.steps().len(), 7);
+    /// ```
+    pub fn dijkstra<C>(
+        &self,
+        start: impl TryInto<Coordinate>,
+        goal: impl TryInto<Coordinate>,
+        connectivity: Connectivity,
+        cost: impl Fn(&T, Coordinate, Coordinate) -> Option<C>,
+    ) -> Option<Path<Coordinate, C>>
+    where
+        C: Cost,
+    {
+        self.astar(start, goal, connectivity, Heuristic::Zero, cost)
+    }
+
+    /// Finds a cheapest path from `start` to `goal` using the A* algorithm.
+    ///
+    /// See [`Grid::dijkstra`] for the cost function.
+    /// The heuristic must not overestimate the remaining cost, or the path may not be the cheapest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Grid, Heuristic};
+    ///
+    /// let grid = Grid::try_from(("...#..#.#..#...".chars(), NonZero::new(5).unwrap())).unwrap();
+    /// let cost = |cell: &char, _, _| (*cell != '#').then_some(1u32);
+    ///
+    /// let path = grid
+    ///     .astar((0, 0), (4, 0), Connectivity::Four, Heuristic::Manhattan(1), cost)
+    ///     .unwrap();
+    /// assert_eq!(path.cost(), 8);
+    ///
+    /// let path = grid
+    ///     .astar((0, 0), (4, 0), Connectivity::Eight, Heuristic::Chebyshev(1), cost)
+    ///     .unwrap();
+    /// assert_eq!(path.cost(), 5);
+    /// ```
+    pub fn astar<C>(
+        &self,
+        start: impl TryInto<Coordinate>,
+        goal: impl TryInto<Coordinate>,
+        connectivity: Connectivity,
+        heuristic: Heuristic<C>,
+        cost: impl Fn(&T, Coordinate, Coordinate) -> Option<C>,
+    ) -> Option<Path<Coordinate, C>>
+    where
+        C: Cost,
+    {
+        let start = self.index_of(start)?;
+        let goal_coordinate = goal.try_into().ok()?;
+        let goal = self.index_of(goal_coordinate)?;
+        let (costs, predecessors) = self.search(
+            start,
+            Some(goal),
+            connectivity,
+            |coordinate| heuristic.estimate(coordinate, goal_coordinate),
+            cost,
+            None,
+        );
+        costs[goal].map(|cost| Path::new(self.trace(&predecessors, goal), cost))
+    }
+
+    /// Returns the cost of the cheapest path from `start` to every cell.
+    ///
+    /// See [`Grid::dijkstra`] for the cost function.
+    /// Unreachable cells, as well as all cells if `start` is not on the grid, have no cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::try_from(("..#.#..#.".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let distances = grid.distances((0, 0), Connectivity::Four, |cell, _, _| {
+    ///     (*cell != '#').then_some(1u32)
+    /// });
+    ///
+    /// assert_eq!(
+    ///     distances.iter().copied().collect::<Vec<_>>(),
+    ///     [Some(0), Some(1), None, Some(1), None, None, Some(2), None, None]
+    /// );
+    /// ```
+    pub fn distances<C>(
+        &self,
+        start: impl TryInto<Coordinate>,
+        connectivity: Connectivity,
+        cost: impl Fn(&T, Coordinate, Coordinate) -> Option<C>,
+    ) -> Grid<Option<C>>
+    where
+        C: Cost,
+    {
+        let costs = self.index_of(start).map_or_else(
+            || vec![None; self.size().get()],
+            |start| {
+                self.search(start, None, connectivity, |_| C::ZERO, cost, None)
+                    .0
+            },
+        );
+
+        #[expect(unsafe_code)]
+        // SAFETY: There is exactly one cost for each cell of the grid.
+        unsafe {
+            Grid::new_unchecked(self.width(), costs)
+        }
+    }
+
+    /// Finds all cheapest paths from `start` to `goal`.
+    ///
+    /// See [`Grid::dijkstra`] for the cost function.
+    /// Note that the amount of paths can grow exponentially with their length,
+    /// for example on large open areas.
+    /// The paths never visit a cell twice, so moves without cost cannot make them loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::try_from((".........".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let paths = grid.all_shortest_paths((0, 0), (2, 2), Connectivity::Four, |_, _, _| Some(1u8));
+    ///
+    /// assert_eq!(paths.len(), 6);
+    /// assert!(paths.iter().all(|path| path.cost() == 4 && path.steps().len() == 5));
+    ///
+    /// let free = grid.all_shortest_paths((0, 0), (1, 1), Connectivity::Four, |_, _, _| Some(0u8));
+    ///
+    /// assert_eq!(free.len(), 8);
+    /// assert!(free.iter().all(|path| path.cost() == 0));
+    /// ```
+    pub fn all_shortest_paths<C>(
+        &self,
+        start: impl TryInto<Coordinate>,
+        goal: impl TryInto<Coordinate>,
+        connectivity: Connectivity,
+        cost: impl Fn(&T, Coordinate, Coordinate) -> Option<C>,
+    ) -> Vec<Path<Coordinate, C>>
+    where
+        C: Cost,
+    {
+        let (Some(start), Some(goal)) = (self.index_of(start), self.index_of(goal)) else {
+            return Vec::new();
+        };
+
+        let mut ties = Vec::new();
+        let (costs, predecessors) = self.search(
+            start,
+            Some(goal),
+            connectivity,
+            |_| C::ZERO,
+            cost,
+            Some(&mut ties),
+        );
+        let Some(total) = costs[goal] else {
+            return Vec::new();
+        };
+
+        ties.retain(|(to, _, cost)| costs[*to] == Some(*cost));
+        ties.sort_unstable_by_key(|(to, from, _)| (*to, *from));
+        let mut paths = Vec::new();
+        let mut partial = vec![vec![goal]];
+
+        while let Some(reversed) = partial.pop() {
+            let last = reversed[reversed.len() - 1];
+
+            if last == start {
+                let steps = reversed
+                    .into_iter()
+                    .rev()
+                    .map(|index| Coordinate::from_width_and_index(self.width(), index))
+                    .collect();
+                paths.push(Path::new(steps, total));
+                continue;
+            }
+
+            let first_tie = ties.partition_point(|(to, _, _)| *to < last);
+            let parents = ties[first_tie..]
+                .iter()
+                .take_while(|(to, _, _)| *to == last)
+                .map(|(_, from, _)| *from);
+
+            for parent in core::iter::once(predecessors[last]).chain(parents) {
+                if reversed.contains(&parent) {
+                    continue;
+                }
+
+                let mut extended = reversed.clone();
+                extended.push(parent);
+                partial.push(extended);
+            }
+        }
+
+        paths
+    }
+
+    /// Finds a cheapest path of states from any of the start states to a goal state
+    /// using Dijkstra's algorithm.
+    ///
+    /// The successor function returns the states reachable from a state, alongside the cost of the move.
+    /// States located outside the grid are ignored.
+    /// The visited states are tracked per cell, so each cell should only hold a few distinct states.
+    /// Returns `None` if no goal state is reachable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Direction, Grid};
+    ///
+    /// let grid = Grid::try_from((".....\
+    ///                            .###.\
+    ///                            .....".chars(), NonZero::new(5).unwrap())).unwrap();
+    /// let start = (Coordinate::new(0, 2), Direction::East);
+    /// let successors = |&(coordinate, direction): &(Coordinate, Direction)| {
+    ///     let forward = coordinate
+    ///         .checked_step_in(&grid, direction)
+    ///         .filter(|next| grid[*next] == '.')
+    ///         .map(|next| ((next, direction), 1));
+    ///     let turns = [direction.turn_left(), direction.turn_right()]
+    ///         .map(|turned| ((coordinate, turned), 1000));
+    ///     forward.into_iter().chain(turns)
+    /// };
+    ///
+    /// let path = grid
+    ///     .dijkstra_states([start], successors, |(coordinate, _)| *coordinate == Coordinate::new(4, 0))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(path.cost(), 1006);
+    /// assert_eq!(path.steps()[5], (Coordinate::new(4, 2), Direction::North));
+    /// ```
+    pub fn dijkstra_states<S, C, I>(
+        &self,
+        starts: impl IntoIterator<Item = S>,
+        successors: impl Fn(&S) -> I,
+        is_goal: impl Fn(&S) -> bool,
+    ) -> Option<Path<S, C>>
+    where
+        S: State,
+        C: Cost,
+        I: IntoIterator<Item = (S, C)>,
+    {
+        self.astar_states(starts, successors, |_| C::ZERO, is_goal)
+    }
+
+    /// Finds a cheapest path of states from any of the start states to a goal state
+    /// using the A* algorithm.
+    ///
+    /// See [`Grid::dijkstra_states`] for the successor function.
+    /// The heuristic estimates the remaining cost from a state to the nearest goal state
+    /// and must not overestimate it, or the path may not be the cheapest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Coordinate, Grid, Heuristic};
+    ///
+    /// // The door `D` can only be passed after picking up the key `k`.
+    /// let grid = Grid::try_from(("k..Dg".chars(), NonZero::new(5).unwrap())).unwrap();
+    /// let goal = Coordinate::new(4, 0);
+    /// let successors = |&(coordinate, key): &(Coordinate, bool)| {
+    ///     coordinate
+    ///         .neighbors_with(Connectivity::Four)
+    ///         .filter_map(|next| grid.get(next).map(|cell| (next, *cell)))
+    ///         .filter(|(_, cell)| *cell != 'D' || key)
+    ///         .map(|(next, cell)| ((next, key || cell == 'k'), 1u32))
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// let heuristic = |(coordinate, _): &(Coordinate, bool)| {
+    ///     Heuristic::Manhattan(1).estimate(*coordinate, goal)
+    /// };
+    ///
+    /// let path = grid
+    ///     .astar_states([(Coordinate::new(1, 0), false)], successors, heuristic, |(c, _)| *c == goal)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(path.cost(), 5);
+    /// assert_eq!(path.steps()[1], (Coordinate::new(0, 0), true));
+    /// ```
+    pub fn astar_states<S, C, I>(
+        &self,
+        starts: impl IntoIterator<Item = S>,
+        successors: impl Fn(&S) -> I,
+        heuristic: impl Fn(&S) -> C,
+        is_goal: impl Fn(&S) -> bool,
+    ) -> Option<Path<S, C>>
+    where
+        S: State,
+        C: Cost,
+        I: IntoIterator<Item = (S, C)>,
+    {
+        let mut nodes: Vec<(S, C, usize)> = Vec::new();
+        let mut buckets: Vec<Vec<usize>> = Vec::new();
+        buckets.resize_with(self.size().get(), Vec::new);
+        let mut heap = BinaryHeap::new();
+
+        for start in starts {
+            let Some(cell) = self.index_of(start.coordinate()) else {
+                continue;
+            };
+
+            if buckets[cell].iter().any(|node| nodes[*node].0 == start) {
+                continue;
+            }
+
+            heap.push(Reverse((heuristic(&start), C::ZERO, nodes.len())));
+            buckets[cell].push(nodes.len());
+            nodes.push((start, C::ZERO, UNREACHED));
+        }
+
+        while let Some(Reverse((_, current, node))) = heap.pop() {
+            if current > nodes[node].1 {
+                continue;
+            }
+
+            if is_goal(&nodes[node].0) {
+                let mut steps = Vec::new();
+                let mut index = node;
+
+                while index != UNREACHED {
+                    steps.push(nodes[index].0.clone());
+                    index = nodes[index].2;
+                }
+
+                steps.reverse();
+                return Some(Path::new(steps, current));
+            }
+
+            for (successor, step) in successors(&nodes[node].0) {
+                let Some(cell) = self.index_of(successor.coordinate()) else {
+                    continue;
+                };
+                let total = current + step;
+                let existing = buckets[cell]
+                    .iter()
+                    .copied()
+                    .find(|other| nodes[*other].0 == successor);
+
+                let next = match existing {
+                    Some(other) if total < nodes[other].1 => {
+                        nodes[other].1 = total;
+                        nodes[other].2 = node;
+                        other
+                    }
+                    Some(_) => continue,
+                    None => {
+                        buckets[cell].push(nodes.len());
+                        nodes.push((successor, total, node));
+                        nodes.len() - 1
+                    }
+                };
+
+                heap.push(Reverse((total + heuristic(&nodes[next].0), total, next)));
+            }
+        }
+
+        None
+    }
+
+    /// Runs Dijkstra's algorithm, or A* if a heuristic is given, from the given start index.
+    ///
+    /// Stops once the goal, if any, has been reached.
+    /// If `ties` is given, it collects additional predecessors that reach a cell at equal cost,
+    /// alongside that cost, and the search continues until all of them have been found.
+    ///
+    /// Returns the cost of reaching each cell and its predecessor on a cheapest path.
+    fn search<C>(
+        &self,
+        start: usize,
+        goal: Option<usize>,
+        connectivity: Connectivity,
+        heuristic: impl Fn(Coordinate) -> C,
+        cost: impl Fn(&T, Coordinate, Coordinate) -> Option<C>,
+        mut ties: Option<&mut Vec<(usize, usize, C)>>,
+    ) -> (Vec<Option<C>>, Vec<usize>)
+    where
+        C: Cost,
+    {
+        let cells: &[T] = self;
+        let mut costs = vec![None; cells.len()];
+        let mut predecessors = vec![UNREACHED; cells.len()];
+        let mut heap = BinaryHeap::new();
+        costs[start] = Some(C::ZERO);
+        predecessors[start] = start;
+        heap.push(Reverse((
+            heuristic(Coordinate::from_width_and_index(self.width(), start)),
+            C::ZERO,
+            start,
+        )));
+
+        while let Some(Reverse((_, current, index))) = heap.pop() {
+            if costs[index].is_some_and(|best| current > best) {
+                continue;
+            }
+
+            if let Some(goal) = goal {
+                if costs[goal].is_some_and(|best| current > best) {
+                    break;
+                }
+
+                if index == goal && ties.is_none() {
+                    break;
+                }
+            }
+
+            let coordinate = Coordinate::from_width_and_index(self.width(), index);
+
+            for neighbor in coordinate.neighbors_with(connectivity) {
+                let Some(next) = self.index_of(neighbor) else {
+                    continue;
+                };
+                let Some(step) = cost(&cells[next], coordinate, neighbor) else {
+                    continue;
+                };
+                let total = current + step;
+
+                match costs[next].map(|best| total.cmp(&best)) {
+                    Some(Ordering::Greater) => {}
+                    Some(Ordering::Equal) => {
+                        if let Some(ties) = ties.as_deref_mut() {
+                            ties.push((next, index, total));
+                        }
+                    }
+                    Some(Ordering::Less) | None => {
+                        costs[next] = Some(total);
+                        predecessors[next] = index;
+                        heap.push(Reverse((total + heuristic(neighbor), total, next)));
+                    }
+                }
+            }
+        }
+
+        (costs, predecessors)
+    }
+
+    /// Follows the predecessors from the given index back to the start and returns the path.
+    fn trace(&self, predecessors: &[usize], mut index: usize) -> Vec<Coordinate> {
+        let mut steps = vec![Coordinate::from_width_and_index(self.width(), index)];
+
+        while predecessors[index] != index {
+            index = predecessors[index];
+            steps.push(Coordinate::from_width_and_index(self.width(), index));
+        }
+
+        steps.reverse();
+        steps
+    }
+
+    fn index_of(&self, coordinate: impl TryInto<Coordinate>) -> Option<usize> {
+        let coordinate = coordinate.try_into().ok()?;
+        self.encompasses(coordinate)
+            .then(|| coordinate.as_index(self.width()))
+            .flatten()
+    }
+}
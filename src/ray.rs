@@ -0,0 +1,336 @@
+use core::iter::FusedIterator;
+use core::num::NonZero;
+
+use crate::cast::{to_f64, to_index};
+use crate::{Coordinate, Grid, Side};
+
+/// A ray through the continuous plane that a grid's cells are laid out in.
+///
+/// By default, the cell at `(x, y)` covers the square from `(x, y)` to `(x + 1, y + 1)`.
+/// The size of the cells and the position of the grid's upper left corner can be changed
+/// with [`Ray::with_cell_size`] and [`Ray::with_grid_origin`].
+///
+/// Distances along the ray are measured in multiples of the length of its direction vector.
+/// Thus, they are euclidean distances if the direction is normalized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    origin: [f64; 2],
+    direction: [f64; 2],
+    cell_size: [f64; 2],
+    grid_origin: [f64; 2],
+}
+
+impl Ray {
+    /// Creates a new ray starting at `origin` and pointing into `direction`.
+    #[must_use]
+    pub const fn new(origin: [f64; 2], direction: [f64; 2]) -> Self {
+        Self {
+            origin,
+            direction,
+            cell_size: [1.0, 1.0],
+            grid_origin: [0.0, 0.0],
+        }
+    }
+
+    /// Sets the width and height of a single cell.
+    #[must_use]
+    pub const fn with_cell_size(mut self, width: f64, height: f64) -> Self {
+        self.cell_size = [width, height];
+        self
+    }
+
+    /// Sets the position of the upper left corner of the grid.
+    #[must_use]
+    pub const fn with_grid_origin(mut self, x: f64, y: f64) -> Self {
+        self.grid_origin = [x, y];
+        self
+    }
+
+    /// Returns the point where the ray starts.
+    #[must_use]
+    pub const fn origin(&self) -> [f64; 2] {
+        self.origin
+    }
+
+    /// Returns the direction of the ray.
+    #[must_use]
+    pub const fn direction(&self) -> [f64; 2] {
+        self.direction
+    }
+
+    /// Returns the point at the given distance along the ray.
+    #[must_use]
+    pub const fn point_at(&self, distance: f64) -> [f64; 2] {
+        [
+            distance * self.direction[0] + self.origin[0],
+            distance * self.direction[1] + self.origin[1],
+        ]
+    }
+
+    /// Yields the cells of a grid with the given dimensions that the ray passes through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Ray, Side};
+    ///
+    /// let width = NonZero::new(4).unwrap();
+    /// let height = NonZero::new(3).unwrap();
+    /// let ray = Ray::new([0.5, 0.5], [1.0, 0.5]);
+    /// let hits: Vec<_> = ray
+    ///     .traverse(width, height)
+    ///     .map(|hit| (hit.coordinate(), hit.distance(), hit.face()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     hits,
+    ///     [
+    ///         (Coordinate::new(0, 0), 0.0, None),
+    ///         (Coordinate::new(1, 0), 0.5, Some(Side::West)),
+    ///         (Coordinate::new(1, 1), 1.0, Some(Side::North)),
+    ///         (Coordinate::new(2, 1), 1.5, Some(Side::West)),
+    ///         (Coordinate::new(3, 1), 2.5, Some(Side::West)),
+    ///         (Coordinate::new(3, 2), 3.0, Some(Side::North)),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn traverse(&self, width: NonZero<usize>, height: NonZero<usize>) -> RayTraversal {
+        RayTraversal::new(self, width, height)
+    }
+}
+
+/// A cell that a ray passes through.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    coordinate: Coordinate,
+    distance: f64,
+    face: Option<Side>,
+}
+
+impl RayHit {
+    /// Returns the coordinate of the cell.
+    #[must_use]
+    pub const fn coordinate(&self) -> Coordinate {
+        self.coordinate
+    }
+
+    /// Returns the distance along the ray at which it enters the cell.
+    #[must_use]
+    pub const fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Returns the side of the cell through which the ray enters it.
+    ///
+    /// This is `None` for the cell that the ray starts in.
+    #[must_use]
+    pub const fn face(&self) -> Option<Side> {
+        self.face
+    }
+}
+
+/// Yields the cells of a grid that a [`Ray`] passes through, in order.
+///
+/// This implements the voxel traversal algorithm by Amanatides and Woo.
+/// If the ray starts outside of the grid, the first cell is the one where it enters the grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RayTraversal {
+    next: Option<RayHit>,
+    dimensions: [usize; 2],
+    forwards: [bool; 2],
+    boundary: [f64; 2],
+    delta: [f64; 2],
+    max_distance: f64,
+}
+
+impl RayTraversal {
+    fn new(ray: &Ray, width: NonZero<usize>, height: NonZero<usize>) -> Self {
+        let dimensions = [width.get(), height.get()];
+        let origin =
+            [0, 1].map(|axis| (ray.origin[axis] - ray.grid_origin[axis]) / ray.cell_size[axis]);
+        let direction = [0, 1].map(|axis| ray.direction[axis] / ray.cell_size[axis]);
+        let forwards = direction.map(|component| component > 0.0);
+        let mut traversal = Self {
+            next: None,
+            dimensions,
+            forwards,
+            boundary: [f64::INFINITY; 2],
+            delta: direction.map(|component| (1.0 / component).abs()),
+            max_distance: f64::INFINITY,
+        };
+
+        let mut enter: f64 = 0.0;
+        let mut exit = f64::INFINITY;
+        let mut face = None;
+
+        for axis in 0..2 {
+            let size = to_f64(dimensions[axis]);
+
+            if direction[axis] == 0.0 {
+                if !(0.0..size).contains(&origin[axis]) {
+                    return traversal;
+                }
+
+                continue;
+            }
+
+            let near = if forwards[axis] { 0.0 } else { size };
+            let far = size - near;
+            let entry = (near - origin[axis]) / direction[axis];
+
+            if entry > enter {
+                enter = entry;
+                face = Some(entry_face(axis, forwards[axis]));
+            }
+
+            exit = exit.min((far - origin[axis]) / direction[axis]);
+        }
+
+        if enter > exit || face.is_some() && enter >= exit {
+            return traversal;
+        }
+
+        let mut cell = [0; 2];
+
+        for axis in 0..2 {
+            let position = enter * direction[axis] + origin[axis];
+            cell[axis] = to_index(position).min(dimensions[axis] - 1);
+
+            if direction[axis] != 0.0 {
+                let boundary = to_f64(cell[axis] + usize::from(forwards[axis]));
+                traversal.boundary[axis] = enter + (boundary - position) / direction[axis];
+            }
+        }
+
+        traversal.next = Some(RayHit {
+            coordinate: cell.into(),
+            distance: enter,
+            face,
+        });
+        traversal
+    }
+
+    /// Stops the traversal at the given distance along the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Ray;
+    ///
+    /// let size = NonZero::new(10).unwrap();
+    /// let ray = Ray::new([0.5, 0.5], [1.0, 0.0]);
+    ///
+    /// assert_eq!(ray.traverse(size, size).count(), 10);
+    /// assert_eq!(ray.traverse(size, size).with_max_distance(3.0).count(), 4);
+    /// ```
+    #[must_use]
+    pub const fn with_max_distance(mut self, max_distance: f64) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+}
+
+impl Iterator for RayTraversal {
+    type Item = RayHit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let hit = self.next.take()?;
+
+        if hit.distance > self.max_distance {
+            return None;
+        }
+
+        let axis = usize::from(self.boundary[1] < self.boundary[0]);
+        let distance = self.boundary[axis];
+
+        if distance.is_finite() {
+            let [x, y] = <[usize; 2]>::from(hit.coordinate);
+            let mut cell = [x, y];
+            let forwards = self.forwards[axis];
+
+            cell[axis] = if forwards {
+                cell[axis] + 1
+            } else {
+                cell[axis].wrapping_sub(1)
+            };
+
+            if cell[axis] < self.dimensions[axis] {
+                self.boundary[axis] += self.delta[axis];
+                self.next = Some(RayHit {
+                    coordinate: cell.into(),
+                    distance,
+                    face: Some(entry_face(axis, forwards)),
+                });
+            }
+        }
+
+        Some(hit)
+    }
+}
+
+impl FusedIterator for RayTraversal {}
+
+impl<T> Grid<T> {
+    /// Yields the cells that the given ray passes through, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Ray};
+    ///
+    /// let grid = Grid::try_from(("abcdefghi".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let ray = Ray::new([-10.0, 25.0], [1.0, -1.0]).with_cell_size(10.0, 10.0);
+    /// let cells: String = grid.ray(&ray).map(|(_, cell)| cell).collect();
+    ///
+    /// assert_eq!(cells, "dab");
+    /// ```
+    pub fn ray(&self, ray: &Ray) -> impl Iterator<Item = (RayHit, &T)> {
+        ray.traverse(self.width(), self.height())
+            .filter_map(|hit| self.get(hit.coordinate).map(|cell| (hit, cell)))
+    }
+
+    /// Returns the first cell along the ray that matches the predicate.
+    ///
+    /// The search stops at `max_distance` along the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Ray, Side};
+    ///
+    /// let grid = Grid::try_from(("....#.#..".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let ray = Ray::new([0.5, 1.5], [1.0, 0.0]);
+    /// let (hit, _) = grid.cast_ray(&ray, f64::INFINITY, |cell| *cell == '#').unwrap();
+    ///
+    /// assert_eq!(hit.coordinate(), Coordinate::new(1, 1));
+    /// assert_eq!(hit.distance(), 0.5);
+    /// assert_eq!(hit.face(), Some(Side::West));
+    /// assert!(grid.cast_ray(&ray, 0.4, |cell| *cell == '#').is_none());
+    /// ```
+    pub fn cast_ray(
+        &self,
+        ray: &Ray,
+        max_distance: f64,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Option<(RayHit, &T)> {
+        ray.traverse(self.width(), self.height())
+            .with_max_distance(max_distance)
+            .filter_map(|hit| self.get(hit.coordinate).map(|cell| (hit, cell)))
+            .find(|(_, cell)| predicate(cell))
+    }
+}
+
+/// Returns the side of a cell through which a ray moving along the given axis enters it.
+const fn entry_face(axis: usize, forwards: bool) -> Side {
+    match (axis, forwards) {
+        (0, true) => Side::West,
+        (0, false) => Side::East,
+        (_, true) => Side::North,
+        (_, false) => Side::South,
+    }
+}
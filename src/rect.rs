@@ -0,0 +1,474 @@
+use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
+use core::num::NonZero;
+
+use crate::cast::to_u128;
+use crate::{Coordinate, CoordinateParseError, Grid, Notation};
+
+/// A non-empty axis-aligned rectangle of cells on a grid.
+///
+/// The rectangle spans from its origin, which is its upper left cell,
+/// `width` cells to the right and `height` cells downwards.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rect {
+    origin: Coordinate,
+    width: NonZero<usize>,
+    height: NonZero<usize>,
+}
+
+impl Rect {
+    /// Creates a new rectangle.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the origin is out of range of a [`Coordinate`]
+    /// or if the lower right cell of the rectangle does not fit into a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::new((1, 2), NonZero::new(3).unwrap(), NonZero::new(4).unwrap());
+    ///
+    /// assert_eq!(rect.origin(), Coordinate::new(1, 2));
+    /// assert_eq!(rect.bottom_right(), Coordinate::new(3, 5));
+    /// assert_eq!(rect.size().unwrap().get(), 12);
+    /// ```
+    pub fn new(
+        origin: impl TryInto<Coordinate>,
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+    ) -> Self {
+        Self::try_new(origin, width, height).expect("rectangle out of range")
+    }
+
+    /// Creates a new rectangle.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `None` if the origin is out of range of a [`Coordinate`]
+    /// or if the lower right cell of the rectangle does not fit into a `usize`.
+    pub fn try_new(
+        origin: impl TryInto<Coordinate>,
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+    ) -> Option<Self> {
+        let origin = origin.try_into().ok()?;
+        origin.x().checked_add(width.get() - 1)?;
+        origin.y().checked_add(height.get() - 1)?;
+        Some(Self {
+            origin,
+            width,
+            height,
+        })
+    }
+
+    /// Creates the smallest rectangle that contains both given cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a cell is out of range of a [`Coordinate`], or if the rectangle spans the whole range
+    /// of `usize` horizontally or vertically, since its width or height does not fit into a `usize` then.
+    /// See [`Rect::try_from_corners`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::from_corners((4, 1), (2, 3));
+    ///
+    /// assert_eq!(rect.origin(), Coordinate::new(2, 1));
+    /// assert_eq!(rect.bottom_right(), Coordinate::new(4, 3));
+    /// ```
+    pub fn from_corners(a: impl TryInto<Coordinate>, b: impl TryInto<Coordinate>) -> Self {
+        Self::try_from_corners(a, b).expect("rectangle out of range")
+    }
+
+    /// Creates the smallest rectangle that contains both given cells.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `None` if a cell is out of range of a [`Coordinate`],
+    /// or if the width or the height of the rectangle does not fit into a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::try_from_corners((4, 1), (2, 3)).unwrap();
+    ///
+    /// assert_eq!(rect.bottom_right(), Coordinate::new(4, 3));
+    /// assert_eq!(Rect::try_from_corners((0, 0), (usize::MAX, 0)), None);
+    /// ```
+    pub fn try_from_corners(
+        a: impl TryInto<Coordinate>,
+        b: impl TryInto<Coordinate>,
+    ) -> Option<Self> {
+        let a = a.try_into().ok()?;
+        let b = b.try_into().ok()?;
+        Some(Self {
+            origin: Coordinate::new(a.x().min(b.x()), a.y().min(b.y())),
+            width: span(a.x(), b.x())?,
+            height: span(a.y(), b.y())?,
+        })
+    }
+
+    /// Parses a rectangle from a range in spreadsheet notation, such as `A1:C5`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CoordinateParseError`] if the string is not a range in spreadsheet notation,
+    /// or if the rectangle is too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::from_a1("A1:C5").unwrap();
+    ///
+    /// assert_eq!(rect, Rect::from_corners((0, 0), (2, 4)));
+    /// ```
+    pub fn from_a1(string: &str) -> Result<Self, CoordinateParseError> {
+        let (a, b) = Coordinate::range_from_a1(string)?;
+        Self::try_from_corners(a, b).ok_or_else(|| CoordinateParseError::OutOfRange {
+            notation: Notation::Spreadsheet,
+            position: string
+                .chars()
+                .position(|char| char == ':')
+                .unwrap_or_default()
+                + 1,
+        })
+    }
+
+    /// Returns the upper left cell of the rectangle.
+    #[must_use]
+    pub const fn origin(&self) -> Coordinate {
+        self.origin
+    }
+
+    /// Returns the lower right cell of the rectangle.
+    #[must_use]
+    pub const fn bottom_right(&self) -> Coordinate {
+        Coordinate::new(
+            self.origin.x() + (self.width.get() - 1),
+            self.origin.y() + (self.height.get() - 1),
+        )
+    }
+
+    /// Returns the width of the rectangle.
+    #[must_use]
+    pub const fn width(&self) -> NonZero<usize> {
+        self.width
+    }
+
+    /// Returns the height of the rectangle.
+    #[must_use]
+    pub const fn height(&self) -> NonZero<usize> {
+        self.height
+    }
+
+    /// Returns the amount of cells in the rectangle.
+    ///
+    /// Returns `None` if the amount of cells does not fit into a `usize`.
+    #[must_use]
+    pub const fn size(&self) -> Option<NonZero<usize>> {
+        self.width.checked_mul(self.height)
+    }
+
+    /// Determines whether the given cell is within the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::{Coordinate32, Rect};
+    ///
+    /// let rect = Rect::from_corners((1, 1), (2, 3));
+    ///
+    /// assert!(rect.contains((1, 1)));
+    /// assert!(rect.contains(Coordinate32::from((2, 2))));
+    /// assert!(rect.contains((2, 3)));
+    /// assert!(!rect.contains((0, 1)));
+    /// assert!(!rect.contains((2, 4)));
+    /// ```
+    pub fn contains(&self, coordinate: impl TryInto<Coordinate>) -> bool {
+        let Ok(coordinate) = coordinate.try_into() else {
+            return false;
+        };
+        let bottom_right = self.bottom_right();
+        (self.origin.x()..=bottom_right.x()).contains(&coordinate.x())
+            && (self.origin.y()..=bottom_right.y()).contains(&coordinate.y())
+    }
+
+    /// Determines whether the other rectangle lies entirely within this rectangle.
+    #[must_use]
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        self.contains(other.origin) && self.contains(other.bottom_right())
+    }
+
+    /// Returns the cells that are in both rectangles, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Rect;
+    ///
+    /// let a = Rect::from_corners((0, 0), (3, 3));
+    /// let b = Rect::from_corners((2, 1), (5, 2));
+    /// let c = Rect::from_corners((4, 4), (5, 5));
+    ///
+    /// assert_eq!(a.intersection(&b), Some(Rect::from_corners((2, 1), (3, 2))));
+    /// assert_eq!(a.intersection(&c), None);
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let own = self.bottom_right();
+        let others = other.bottom_right();
+        let origin = Coordinate::new(
+            self.origin.x().max(other.origin.x()),
+            self.origin.y().max(other.origin.y()),
+        );
+        let bottom_right = Coordinate::new(own.x().min(others.x()), own.y().min(others.y()));
+
+        if origin.x() > bottom_right.x() || origin.y() > bottom_right.y() {
+            return None;
+        }
+
+        Some(Self::from_corners(origin, bottom_right))
+    }
+
+    /// Returns the smallest rectangle that contains both rectangles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangles reach both ends of the range of `usize`, see [`Rect::from_corners`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Rect;
+    ///
+    /// let a = Rect::from_corners((0, 1), (1, 2));
+    /// let b = Rect::from_corners((4, 0), (5, 1));
+    ///
+    /// assert_eq!(a.union_bounds(&b), Rect::from_corners((0, 0), (5, 2)));
+    /// ```
+    #[must_use]
+    pub fn union_bounds(&self, other: &Self) -> Self {
+        let own = self.bottom_right();
+        let others = other.bottom_right();
+        Self::from_corners(
+            (
+                self.origin.x().min(other.origin.x()),
+                self.origin.y().min(other.origin.y()),
+            ),
+            (own.x().max(others.x()), own.y().max(others.y())),
+        )
+    }
+
+    /// Grows the rectangle by `amount` cells on every side.
+    ///
+    /// The rectangle stops growing at the boundaries of the range of `usize`,
+    /// and once it is `usize::MAX` cells wide or high.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Rect;
+    ///
+    /// let rect = Rect::from_corners((1, 2), (3, 4));
+    ///
+    /// assert_eq!(rect.expand(1), Rect::from_corners((0, 1), (4, 5)));
+    /// assert_eq!(rect.expand(2), Rect::from_corners((0, 0), (5, 6)));
+    /// assert_eq!(rect.expand(usize::MAX).width().get(), usize::MAX);
+    /// ```
+    #[must_use]
+    pub fn expand(&self, amount: usize) -> Self {
+        let grown = |first: usize, last: usize| {
+            let first = first.saturating_sub(amount);
+            let last = last
+                .saturating_add(amount)
+                .min(first.saturating_add(usize::MAX - 1));
+            (first, last)
+        };
+        let bottom_right = self.bottom_right();
+        let (left, right) = grown(self.origin.x(), bottom_right.x());
+        let (top, bottom) = grown(self.origin.y(), bottom_right.y());
+        Self::from_corners((left, top), (right, bottom))
+    }
+
+    /// Shrinks the rectangle by `amount` cells on every side.
+    ///
+    /// Returns `None` if no cells remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Rect;
+    ///
+    /// let rect = Rect::from_corners((1, 2), (5, 4));
+    ///
+    /// assert_eq!(rect.shrink(1), Some(Rect::from_corners((2, 3), (4, 3))));
+    /// assert_eq!(rect.shrink(2), None);
+    /// assert_eq!(rect.shrink(usize::MAX), None);
+    /// ```
+    #[must_use]
+    pub fn shrink(&self, amount: usize) -> Option<Self> {
+        let shrunk = |length: NonZero<usize>| {
+            amount
+                .checked_mul(2)
+                .and_then(|margin| length.get().checked_sub(margin))
+                .and_then(NonZero::new)
+        };
+
+        let width = shrunk(self.width)?;
+        let height = shrunk(self.height)?;
+
+        Some(Self {
+            origin: Coordinate::new(self.origin.x() + amount, self.origin.y() + amount),
+            width,
+            height,
+        })
+    }
+
+    /// Returns the part of the rectangle that lies on the given grid, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Rect};
+    ///
+    /// let grid = Grid::new(NonZero::new(4).unwrap(), NonZero::new(3).unwrap(), || 0);
+    ///
+    /// assert_eq!(
+    ///     Rect::from_corners((2, 1), (6, 6)).clip_to(&grid),
+    ///     Some(Rect::from_corners((2, 1), (3, 2)))
+    /// );
+    /// assert_eq!(Rect::from_corners((4, 0), (6, 6)).clip_to(&grid), None);
+    /// ```
+    #[must_use]
+    pub fn clip_to<T>(&self, grid: &Grid<T>) -> Option<Self> {
+        self.intersection(&grid.bounds())
+    }
+
+    /// Yields the coordinates of the rectangle's cells in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::from_corners((1, 1), (2, 2));
+    /// let coordinates: Vec<_> = rect.coordinates().collect();
+    ///
+    /// assert_eq!(
+    ///     coordinates,
+    ///     [
+    ///         Coordinate::new(1, 1),
+    ///         Coordinate::new(2, 1),
+    ///         Coordinate::new(1, 2),
+    ///         Coordinate::new(2, 2),
+    ///     ]
+    /// );
+    /// assert_eq!(rect.coordinates().size_hint(), (4, Some(4)));
+    ///
+    /// let max = NonZero::new(usize::MAX).unwrap();
+    /// let mut huge = Rect::new((0, 0), max, max).coordinates();
+    ///
+    /// assert_eq!(huge.size_hint(), (usize::MAX, None));
+    /// assert_eq!(huge.next_back(), Some(Coordinate::new(usize::MAX - 1, usize::MAX - 1)));
+    /// ```
+    #[must_use]
+    pub fn coordinates(&self) -> RectCoordinates {
+        RectCoordinates {
+            rect: *self,
+            front: 0,
+            back: to_u128(self.width.get()) * to_u128(self.height.get()),
+        }
+    }
+}
+
+impl Display for Rect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.origin, self.bottom_right())
+    }
+}
+
+impl IntoIterator for Rect {
+    type Item = Coordinate;
+    type IntoIter = RectCoordinates;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coordinates()
+    }
+}
+
+/// Iterator over the coordinates of a [`Rect`]'s cells in row-major order.
+///
+/// Since a rectangle may contain more cells than fit into a `usize`, the iterator does not implement
+/// [`ExactSizeIterator`]. Its [`Iterator::size_hint`] is exact whenever the remaining amount fits.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RectCoordinates {
+    rect: Rect,
+    front: u128,
+    back: u128,
+}
+
+impl RectCoordinates {
+    fn coordinate(&self, index: u128) -> Coordinate {
+        let width = to_u128(self.rect.width.get());
+        // The offsets are within the rectangle, whose cells all fit into a `usize`.
+        Coordinate::new(
+            self.rect.origin.x() + (index % width) as usize,
+            self.rect.origin.y() + (index / width) as usize,
+        )
+    }
+}
+
+impl Iterator for RectCoordinates {
+    type Item = Coordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let coordinate = self.coordinate(self.front);
+        self.front += 1;
+        Some(coordinate)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::try_from(self.back - self.front).ok();
+        (len.unwrap_or(usize::MAX), len)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = (self.front + to_u128(n)).min(self.back);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for RectCoordinates {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.coordinate(self.back))
+    }
+}
+
+impl FusedIterator for RectCoordinates {}
+
+/// Returns the amount of cells from `a` to `b`, including both, if it fits into a `usize`.
+const fn span(a: usize, b: usize) -> Option<NonZero<usize>> {
+    NonZero::<usize>::MIN.checked_add(a.abs_diff(b))
+}
@@ -0,0 +1,257 @@
+use alloc::vec::Vec;
+
+use crate::cast::to_f64;
+use crate::{Connectivity, Coordinate, Grid, Rect, Side};
+
+const CORNER_OFFSETS: [(isize, isize); 4] = [(-1, -1), (1, -1), (1, 1), (-1, 1)];
+
+/// Statistics of a connected region of cells on a grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Region {
+    label: usize,
+    start: Coordinate,
+    area: usize,
+    perimeter: usize,
+    sides: usize,
+    holes: usize,
+    bounds: Rect,
+    centroid: [f64; 2],
+}
+
+impl Region {
+    /// Returns the ID of the region, as assigned by [`Grid::label_components`].
+    #[must_use]
+    pub const fn label(&self) -> usize {
+        self.label
+    }
+
+    /// Returns the first cell of the region in row-major order.
+    ///
+    /// This can be used as the start of a [`Grid::flood_fill`] to retrieve all cells of the region.
+    #[must_use]
+    pub const fn start(&self) -> Coordinate {
+        self.start
+    }
+
+    /// Returns the amount of cells in the region.
+    #[must_use]
+    pub const fn area(&self) -> usize {
+        self.area
+    }
+
+    /// Returns the amount of cell sides that separate the region from other cells or the outside of the grid.
+    #[must_use]
+    pub const fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// Returns the amount of straight sides of the region's outline, including the outlines of its holes.
+    ///
+    /// This equals the amount of corners of the outline.
+    #[must_use]
+    pub const fn sides(&self) -> usize {
+        self.sides
+    }
+
+    /// Returns the amount of holes that the region encloses.
+    ///
+    /// A hole is a connected area of other cells, that cannot reach the outside of the region.
+    /// It is connected with the opposite connectivity of the region, see [`Connectivity::dual`].
+    #[must_use]
+    pub const fn holes(&self) -> usize {
+        self.holes
+    }
+
+    /// Returns the smallest rectangle that contains the region.
+    #[must_use]
+    pub const fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Returns the mean of the centers of the region's cells.
+    ///
+    /// The cell at `(x, y)` has its center at `(x + 0.5, y + 0.5)`.
+    #[must_use]
+    pub const fn centroid(&self) -> [f64; 2] {
+        self.centroid
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the statistics of all connected regions of the grid.
+    ///
+    /// Two adjacent cells belong to the same region if `eq` returns `true` for their values.
+    /// The regions are ordered by their labels, as assigned by [`Grid::label_components`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Coordinate, Grid};
+    ///
+    /// let grid = Grid::try_from(("AAAAABBAAAAACCDD".chars(), NonZero::new(4).unwrap())).unwrap();
+    /// let regions = grid.regions(Connectivity::Four, PartialEq::eq);
+    ///
+    /// assert_eq!(regions.len(), 4);
+    /// assert_eq!(regions[0].area(), 10);
+    /// assert_eq!(regions[0].perimeter(), 20);
+    /// assert_eq!(regions[0].sides(), 8);
+    /// assert_eq!(regions[0].holes(), 1);
+    /// assert_eq!(regions[0].bounds().bottom_right(), Coordinate::new(3, 2));
+    /// assert_eq!(regions[1].start(), Coordinate::new(1, 1));
+    /// assert_eq!(regions[1].perimeter(), 6);
+    /// assert_eq!(regions[1].sides(), 4);
+    /// assert_eq!(regions[1].centroid(), [2.0, 1.5]);
+    /// ```
+    pub fn regions(&self, connectivity: Connectivity, eq: impl Fn(&T, &T) -> bool) -> Vec<Region> {
+        let (labels, count) = self.label_components(connectivity, eq);
+        region_statistics(&labels, count, connectivity)
+    }
+
+    /// Returns the statistics of all connected regions of cells that match the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::try_from(("###.#.#.###....#".chars(), NonZero::new(4).unwrap())).unwrap();
+    ///
+    /// let islands = grid.regions_where(Connectivity::Four, |cell| *cell == '#');
+    /// assert_eq!(islands.iter().map(|island| island.area()).collect::<Vec<_>>(), [8, 1]);
+    /// assert_eq!(islands[0].holes(), 1);
+    ///
+    /// let islands = grid.regions_where(Connectivity::Eight, |cell| *cell == '#');
+    /// assert_eq!(islands.len(), 1);
+    /// assert_eq!(islands[0].area(), 9);
+    /// assert_eq!(islands[0].holes(), 1);
+    /// ```
+    pub fn regions_where(
+        &self,
+        connectivity: Connectivity,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Vec<Region> {
+        let mut regions = self.regions(connectivity, |lhs, rhs| predicate(lhs) == predicate(rhs));
+        regions.retain(|region| self.get(region.start).is_some_and(&predicate));
+        regions
+    }
+}
+
+/// Accumulates the statistics of a single region while scanning the grid.
+struct Accumulator {
+    start: Coordinate,
+    area: usize,
+    perimeter: usize,
+    sides: usize,
+    single_corners: usize,
+    triple_corners: usize,
+    diagonal_corners: usize,
+    sum: [f64; 2],
+    min: [usize; 2],
+    max: [usize; 2],
+}
+
+impl Accumulator {
+    const fn new(start: Coordinate) -> Self {
+        Self {
+            start,
+            area: 0,
+            perimeter: 0,
+            sides: 0,
+            single_corners: 0,
+            triple_corners: 0,
+            diagonal_corners: 0,
+            sum: [0.0; 2],
+            min: [start.x(), start.y()],
+            max: [start.x(), start.y()],
+        }
+    }
+}
+
+fn region_statistics(
+    labels: &Grid<usize>,
+    count: usize,
+    connectivity: Connectivity,
+) -> Vec<Region> {
+    let mut accumulators: Vec<Accumulator> = Vec::with_capacity(count);
+
+    for (coordinate, &label) in labels.enumerate() {
+        if label == accumulators.len() {
+            accumulators.push(Accumulator::new(coordinate));
+        }
+
+        let accumulator = &mut accumulators[label];
+        let same = |offset: (isize, isize)| {
+            (&coordinate + &offset).and_then(|neighbor| labels.get(neighbor)) == Some(&label)
+        };
+
+        accumulator.area += 1;
+        accumulator.sum[0] += to_f64(coordinate.x());
+        accumulator.sum[1] += to_f64(coordinate.y());
+        accumulator.min = [
+            accumulator.min[0].min(coordinate.x()),
+            accumulator.min[1].min(coordinate.y()),
+        ];
+        accumulator.max = [
+            accumulator.max[0].max(coordinate.x()),
+            accumulator.max[1].max(coordinate.y()),
+        ];
+        accumulator.perimeter += Side::ALL.iter().filter(|side| !same(side.offset())).count();
+
+        for &(dx, dy) in &CORNER_OFFSETS {
+            let horizontal = same((dx, 0));
+            let vertical = same((0, dy));
+            let diagonal = same((dx, dy));
+
+            if horizontal == vertical && !(horizontal && diagonal) {
+                accumulator.sides += 1;
+            }
+
+            match (horizontal, vertical, diagonal) {
+                (false, false, false) => accumulator.single_corners += 1,
+                (false, false, true) => accumulator.diagonal_corners += 1,
+                (true, true, false) | (true, false, true) | (false, true, true) => {
+                    accumulator.triple_corners += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    accumulators
+        .into_iter()
+        .enumerate()
+        .map(|(label, accumulator)| {
+            let area = to_f64(accumulator.area);
+            let bounds = Rect::from_corners(accumulator.min, accumulator.max);
+            Region {
+                label,
+                start: accumulator.start,
+                area: accumulator.area,
+                perimeter: accumulator.perimeter,
+                sides: accumulator.sides,
+                holes: count_holes(&accumulator, connectivity),
+                bounds,
+                centroid: accumulator.sum.map(|sum| sum / area + 0.5),
+            }
+        })
+        .collect()
+}
+
+/// Counts the holes of a connected region from the 2×2 blocks of cells around its corners.
+///
+/// The Euler number of the region, its amount of components minus its amount of holes,
+/// follows from how many blocks contain one, three or two diagonal cells of the region (Gray's bit quads).
+/// Every cell sees its blocks through its own corners, so a block with `k` cells of the region
+/// is counted `k` times, which is accounted for by scaling the formula by 12.
+const fn count_holes(accumulator: &Accumulator, connectivity: Connectivity) -> usize {
+    let single = 3 * accumulator.single_corners;
+    let triple = accumulator.triple_corners;
+    let diagonal = 3 * accumulator.diagonal_corners;
+
+    match connectivity {
+        Connectivity::Four => (12 + triple - single - diagonal) / 12,
+        Connectivity::Eight => (12 + triple + diagonal - single) / 12,
+    }
+}
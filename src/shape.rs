@@ -0,0 +1,642 @@
+use alloc::vec::Vec;
+use core::num::NonZero;
+
+use crate::bisect::partition_point;
+use crate::cast::{to_f64, to_i128, to_index, to_u128};
+use crate::{Bresenham, Coordinate, Grid, Rect};
+
+/// A geometric shape that can be rasterized onto a grid.
+///
+/// Continuous positions follow the convention of [`Ray`](crate::Ray): the cell at `(x, y)` covers the square
+/// from `(x, y)` to `(x + 1, y + 1)`, so its center is the point `(x + 0.5, y + 0.5)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    /// A circle around the given cell, rasterized with the midpoint circle algorithm.
+    Circle {
+        /// The cell at the center of the circle.
+        center: Coordinate,
+        /// The radius in cells.
+        radius: usize,
+    },
+    /// An axis-aligned ellipse around the given cell, rasterized with the midpoint ellipse algorithm.
+    Ellipse {
+        /// The cell at the center of the ellipse.
+        center: Coordinate,
+        /// The horizontal radius in cells.
+        radius_x: usize,
+        /// The vertical radius in cells.
+        radius_y: usize,
+    },
+    /// An axis-aligned rectangle.
+    Rectangle(Rect),
+    /// A rectangle rotated around its center.
+    ///
+    /// A cell is covered if its center lies within the rectangle.
+    RotatedRectangle {
+        /// The center of the rectangle.
+        center: [f64; 2],
+        /// Half of the width and height of the rectangle.
+        half_extents: [f64; 2],
+        /// The direction of the rectangle's width, which need not be normalized.
+        axis: [f64; 2],
+    },
+    /// A closed polygon with edges between the centers of the given cells.
+    ///
+    /// The interior is determined with the even-odd rule.
+    Polygon(Vec<Coordinate>),
+}
+
+impl Shape {
+    /// Returns the cells covered by the filled shape within the given bounds.
+    ///
+    /// The cells are yielded row by row from left to right, each of them once.
+    /// Only the rows and columns of the shape within the bounds are visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Rect, Shape};
+    ///
+    /// let size = NonZero::new(5).unwrap();
+    /// let bounds = Rect::new((0, 0), size, size);
+    /// let circle = Shape::Circle { center: Coordinate::new(0, 0), radius: 2 };
+    ///
+    /// assert_eq!(
+    ///     circle.filled(&bounds).map(<(usize, usize)>::from).collect::<Vec<_>>(),
+    ///     [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1), (0, 2), (1, 2)]
+    /// );
+    ///
+    /// let huge = Shape::Circle { center: Coordinate::new(2, 2), radius: usize::MAX / 4 };
+    /// assert_eq!(huge.filled(&bounds).count(), 25);
+    ///
+    /// let rotated = Shape::RotatedRectangle {
+    ///     center: [2.5, 2.5],
+    ///     half_extents: [1.0, 1.0],
+    ///     axis: [1.0, 0.0],
+    /// };
+    /// let corners = [(1, 1), (3, 1), (3, 3), (1, 3)];
+    /// let polygon = Shape::Polygon(corners.into_iter().map(Coordinate::from).collect());
+    ///
+    /// assert!(rotated.filled(&bounds).eq(polygon.filled(&bounds)));
+    /// ```
+    pub fn filled(&self, bounds: &Rect) -> impl Iterator<Item = Coordinate> + use<'_> {
+        self.cells(bounds, true)
+    }
+
+    /// Returns the cells on the outline of the shape within the given bounds.
+    ///
+    /// The cells are yielded in the same order as by [`Shape::filled`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Rect, Shape};
+    ///
+    /// let size = NonZero::new(5).unwrap();
+    /// let bounds = Rect::new((0, 0), size, size);
+    /// let three = NonZero::new(3).unwrap();
+    /// let rect = Shape::Rectangle(Rect::new(Coordinate::new(1, 1), three, three));
+    ///
+    /// assert_eq!(rect.outline(&bounds).count(), 8);
+    /// assert!(rect.outline(&bounds).all(|cell| cell != Coordinate::new(2, 2)));
+    ///
+    /// let corner = Rect::new((2, 2), size, size);
+    /// assert_eq!(
+    ///     rect.outline(&corner).map(<(usize, usize)>::from).collect::<Vec<_>>(),
+    ///     [(3, 2), (2, 3), (3, 3)]
+    /// );
+    /// ```
+    pub fn outline(&self, bounds: &Rect) -> impl Iterator<Item = Coordinate> + use<'_> {
+        self.cells(bounds, false)
+    }
+
+    fn cells(&self, bounds: &Rect, filled: bool) -> impl Iterator<Item = Coordinate> + use<'_> {
+        let shape = Prepared::new(self);
+        let bounds = *bounds;
+        let rows = shape.extent().and_then(|(top, bottom)| {
+            let top = usize::try_from(top.max(to_i128(bounds.origin().y()))).ok()?;
+            let bottom = usize::try_from(bottom.min(to_i128(bounds.bottom_right().y()))).ok()?;
+            Some(top..=bottom)
+        });
+
+        rows.into_iter().flatten().flat_map(move |y| {
+            merge(shape.spans(y, &bounds, filled), &bounds)
+                .into_iter()
+                .flat_map(move |(first, last)| (first..=last).map(move |x| Coordinate::new(x, y)))
+        })
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Sets all cells covered by the filled shape to the given value.
+    ///
+    /// Parts of the shape outside the grid are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Shape};
+    ///
+    /// let mut grid = Grid::new(NonZero::new(7).unwrap(), NonZero::new(7).unwrap(), || '.');
+    /// grid.fill_shape(&Shape::Circle { center: Coordinate::new(3, 3), radius: 3 }, '#');
+    /// let rows: Vec<String> = grid.rows().map(Iterator::collect).collect();
+    ///
+    /// assert_eq!(
+    ///     rows,
+    ///     ["..###..", ".#####.", "#######", "#######", "#######", ".#####.", "..###.."]
+    /// );
+    ///
+    /// let mut grid = Grid::new(NonZero::new(6).unwrap(), NonZero::new(5).unwrap(), || '.');
+    /// let triangle = vec![Coordinate::new(0, 0), Coordinate::new(5, 4), Coordinate::new(0, 4)];
+    /// grid.fill_shape(&Shape::Polygon(triangle), '#');
+    /// let rows: Vec<String> = grid.rows().map(Iterator::collect).collect();
+    ///
+    /// assert_eq!(rows, ["#.....", "##....", "####..", "#####.", "######"]);
+    /// ```
+    pub fn fill_shape(&mut self, shape: &Shape, value: T) {
+        for coordinate in shape.filled(&self.bounds()) {
+            self[coordinate].clone_from(&value);
+        }
+    }
+
+    /// Sets all cells on the outline of the shape to the given value.
+    ///
+    /// Parts of the shape outside the grid are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Grid, Shape};
+    ///
+    /// let mut grid = Grid::new(NonZero::new(9).unwrap(), NonZero::new(5).unwrap(), || '.');
+    /// let ellipse = Shape::Ellipse { center: Coordinate::new(4, 2), radius_x: 4, radius_y: 2 };
+    /// grid.draw_shape(&ellipse, '#');
+    /// let rows: Vec<String> = grid.rows().map(Iterator::collect).collect();
+    ///
+    /// assert_eq!(rows, ["..#####..", ".#.....#.", "#.......#", ".#.....#.", "..#####.."]);
+    /// ```
+    pub fn draw_shape(&mut self, shape: &Shape, value: T) {
+        for coordinate in shape.outline(&self.bounds()) {
+            self[coordinate].clone_from(&value);
+        }
+    }
+}
+
+/// A shape with everything that does not depend on the row computed in advance.
+enum Prepared<'shape> {
+    Circle(Coordinate, Circle),
+    Ellipse(Coordinate, Ellipse),
+    Rectangle(Rect),
+    RotatedRectangle(RotatedRectangle),
+    Polygon(&'shape [Coordinate]),
+}
+
+impl<'shape> Prepared<'shape> {
+    fn new(shape: &'shape Shape) -> Self {
+        match shape {
+            Shape::Circle { center, radius } => Self::Circle(*center, Circle::new(*radius)),
+            Shape::Ellipse {
+                center,
+                radius_x,
+                radius_y,
+            } => Self::Ellipse(*center, Ellipse::new(*radius_x, *radius_y)),
+            Shape::Rectangle(rect) => Self::Rectangle(*rect),
+            Shape::RotatedRectangle {
+                center,
+                half_extents,
+                axis,
+            } => Self::RotatedRectangle(RotatedRectangle::new(*center, *half_extents, *axis)),
+            Shape::Polygon(vertices) => Self::Polygon(vertices),
+        }
+    }
+
+    /// Returns the first and the last row that the shape may cover.
+    fn extent(&self) -> Option<(i128, i128)> {
+        match self {
+            Self::Circle(center, Circle { radius, .. })
+            | Self::Ellipse(
+                center,
+                Ellipse {
+                    radius_y: radius, ..
+                },
+            ) => {
+                let (y, radius) = (to_i128(center.y()), radius.cast_signed());
+                Some((y - radius, y + radius))
+            }
+            Self::Rectangle(rect) => {
+                Some((to_i128(rect.origin().y()), to_i128(rect.bottom_right().y())))
+            }
+            Self::RotatedRectangle(rectangle) => {
+                let (top, bottom) = rectangle.candidates(1);
+                Some((to_i128(top), to_i128(bottom)))
+            }
+            Self::Polygon(vertices) => {
+                let top = vertices.iter().map(Coordinate::y).min()?;
+                let bottom = vertices.iter().map(Coordinate::y).max()?;
+                Some((to_i128(top), to_i128(bottom)))
+            }
+        }
+    }
+
+    /// Returns the first and the last column of each span of cells in the given row.
+    ///
+    /// The spans may overlap and reach beyond the bounds,
+    /// which only limit the search for the cells of rotated rectangles.
+    fn spans(&self, y: usize, bounds: &Rect, filled: bool) -> Vec<(i128, i128)> {
+        let mut spans = Vec::new();
+
+        match self {
+            Self::Circle(center, circle) => {
+                round(
+                    *center,
+                    &circle.row(to_u128(y.abs_diff(center.y()))),
+                    filled,
+                    &mut spans,
+                );
+            }
+            Self::Ellipse(center, ellipse) => {
+                round(
+                    *center,
+                    &ellipse.row(to_u128(y.abs_diff(center.y()))),
+                    filled,
+                    &mut spans,
+                );
+            }
+            Self::Rectangle(rect) => {
+                let (left, top) = coordinate_to_i128(rect.origin());
+                let (right, bottom) = coordinate_to_i128(rect.bottom_right());
+                let y = to_i128(y);
+
+                if filled || y == top || y == bottom {
+                    spans.push((left, right));
+                } else {
+                    spans.extend([(left, left), (right, right)]);
+                }
+            }
+            Self::RotatedRectangle(rectangle) => {
+                let (left, right) = rectangle.candidates(0);
+
+                for x in left.max(bounds.origin().x())..=right.min(bounds.bottom_right().x()) {
+                    let coordinate = Coordinate::new(x, y);
+
+                    if rectangle.contains(cell_center(coordinate))
+                        && (filled || rectangle.is_on_outline(coordinate))
+                    {
+                        spans.push((to_i128(x), to_i128(x)));
+                    }
+                }
+            }
+            Self::Polygon(vertices) => {
+                if filled {
+                    scanline(vertices, y, &mut spans);
+                }
+
+                edges(vertices, y, bounds, &mut spans);
+            }
+        }
+
+        spans
+    }
+}
+
+/// A rotated rectangle, with its axis and the corresponding bounds precomputed.
+struct RotatedRectangle {
+    center: [f64; 2],
+    axes: [[f64; 2]; 2],
+    bounds: [f64; 2],
+    reach: f64,
+}
+
+impl RotatedRectangle {
+    fn new(center: [f64; 2], half_extents: [f64; 2], axis: [f64; 2]) -> Self {
+        let [x, y] = axis;
+        let length = x * x + y * y;
+        Self {
+            center,
+            axes: [axis, [-y, x]],
+            bounds: half_extents.map(|extent| extent * extent * length),
+            reach: half_extents[0].abs() + half_extents[1].abs(),
+        }
+    }
+
+    /// Determines whether the given point lies within the rectangle.
+    ///
+    /// This compares squared projections onto the unnormalized axes,
+    /// which avoids computing the length of the axis.
+    fn contains(&self, point: [f64; 2]) -> bool {
+        let offset = [point[0] - self.center[0], point[1] - self.center[1]];
+        self.axes.iter().zip(self.bounds).all(|(axis, bound)| {
+            let projection = offset[0] * axis[0] + offset[1] * axis[1];
+            projection * projection <= bound
+        })
+    }
+
+    /// Determines whether a covered cell has an orthogonal neighbor that is not covered.
+    fn is_on_outline(&self, coordinate: Coordinate) -> bool {
+        let [x, y] = cell_center(coordinate);
+        [[x - 1.0, y], [x + 1.0, y], [x, y - 1.0], [x, y + 1.0]]
+            .into_iter()
+            .any(|neighbor| !self.contains(neighbor))
+    }
+
+    /// Returns the first and the last column or row, depending on the axis,
+    /// whose cell centers may lie within the rectangle.
+    fn candidates(&self, axis: usize) -> (usize, usize) {
+        (
+            to_index((self.center[axis] - self.reach - 0.5).max(0.0)),
+            to_index((self.center[axis] + self.reach - 0.5).max(0.0)),
+        )
+    }
+}
+
+/// The cells of a circle or an ellipse in one row of its lower right quadrant,
+/// given as horizontal distances from its center.
+#[derive(Default)]
+struct QuadrantRow {
+    /// The cell that the midpoint algorithm reaches with a vertical step.
+    point: Option<u128>,
+    /// The first and last cell of a run that the midpoint algorithm walks horizontally.
+    run: Option<(u128, u128)>,
+}
+
+impl QuadrantRow {
+    /// Returns the distance of the outermost cell.
+    fn widest(&self) -> Option<u128> {
+        self.point.max(self.run.map(|(_, last)| last))
+    }
+}
+
+/// Adds the spans of a row of a circle or an ellipse, mirroring the cells of its lower right quadrant.
+fn round(center: Coordinate, cells: &QuadrantRow, filled: bool, spans: &mut Vec<(i128, i128)>) {
+    let x = to_i128(center.x());
+
+    if filled {
+        if let Some(widest) = cells.widest() {
+            let widest = widest.cast_signed();
+            spans.push((x - widest, x + widest));
+        }
+
+        return;
+    }
+
+    if let Some(point) = cells.point {
+        let point = point.cast_signed();
+        spans.extend([(x - point, x - point), (x + point, x + point)]);
+    }
+
+    if let Some((first, last)) = cells.run {
+        let (first, last) = (first.cast_signed(), last.cast_signed());
+        spans.extend([(x + first, x + last), (x - last, x - first)]);
+    }
+}
+
+/// The cells of the midpoint circle algorithm, computed for each row on demand.
+///
+/// The algorithm walks the first octant, where `y` counts up from zero and `x` down from the radius,
+/// keeping `x` while the midpoint `(x - 1/2, y)` lies within the circle.
+/// The other octants are mirror images.
+struct Circle {
+    radius: u128,
+    /// The largest `y` of the first octant.
+    last: u128,
+}
+
+impl Circle {
+    fn new(radius: usize) -> Self {
+        let mut circle = Self {
+            radius: to_u128(radius),
+            last: 0,
+        };
+        circle.last = partition_point(circle.radius + 1, |y| {
+            circle.octant_x(y).is_some_and(|x| x >= y)
+        }) - 1;
+        circle
+    }
+
+    /// Returns the `x` that the algorithm chooses for the given `y`,
+    /// which is the largest `x` with `x * (x - 1) < radius² - y²`.
+    fn octant_x(&self, y: u128) -> Option<u128> {
+        if y == 0 {
+            return Some(self.radius);
+        }
+
+        let remainder = (self.radius * self.radius)
+            .checked_sub(y * y)
+            .filter(|remainder| *remainder > 0)?;
+        let root = remainder.isqrt();
+        Some(if root * (root + 1) < remainder {
+            root + 1
+        } else {
+            root
+        })
+    }
+
+    fn row(&self, y: u128) -> QuadrantRow {
+        let count = self.last + 1;
+        let first = partition_point(count, |dy| self.octant_x(dy).is_some_and(|x| x > y));
+        let end = partition_point(count, |dy| self.octant_x(dy).is_some_and(|x| x >= y));
+        QuadrantRow {
+            point: if y <= self.last {
+                self.octant_x(y)
+            } else {
+                None
+            },
+            run: (first < end).then(|| (first, end - 1)),
+        }
+    }
+}
+
+/// The cells of the midpoint ellipse algorithm, computed for each row on demand.
+///
+/// The algorithm walks the first quadrant in two regions. In the first region,
+/// `x` counts up from zero while `y` is kept as long as the midpoint `(x, y - 1/2)` lies within the ellipse.
+/// Once the slope exceeds one, `y` counts down to zero
+/// while `x` is kept as long as the midpoint `(x + 1/2, y)` lies outside of the ellipse.
+/// The products saturate, so the cells are exact as long as the product of the radii fits into 64 bits.
+struct Ellipse {
+    radius_x: u128,
+    radius_y: u128,
+    /// The first cell of the second region.
+    turn: (u128, u128),
+}
+
+impl Ellipse {
+    fn new(radius_x: usize, radius_y: usize) -> Self {
+        let mut ellipse = Self {
+            radius_x: to_u128(radius_x),
+            radius_y: to_u128(radius_y),
+            turn: (0, 0),
+        };
+        let (rx2, ry2) = ellipse.squared_radii();
+        let x = partition_point(ellipse.radius_x + 1, |x| {
+            let y = ellipse.first_region_y(x).unwrap_or_default();
+            ry2.saturating_mul(x) < rx2.saturating_mul(y)
+        });
+        // The first region still steps to the turning point, where it may decrease `y` by one at most.
+        let y = x.checked_sub(1).map_or(ellipse.radius_y, |previous| {
+            let y = ellipse.first_region_y(previous).unwrap_or_default();
+            if ellipse.is_inside_below(x, y) {
+                y
+            } else {
+                y.saturating_sub(1)
+            }
+        });
+        ellipse.turn = (x, y);
+        ellipse
+    }
+
+    const fn squared_radii(&self) -> (u128, u128) {
+        (self.radius_x * self.radius_x, self.radius_y * self.radius_y)
+    }
+
+    /// Determines whether the midpoint `(x, y - 1/2)` lies within the ellipse.
+    const fn is_inside_below(&self, x: u128, y: u128) -> bool {
+        let (rx2, ry2) = self.squared_radii();
+        let left = ry2
+            .saturating_mul(x * x)
+            .saturating_add(rx2.saturating_mul(y * y.saturating_sub(1)))
+            .saturating_add(rx2 / 4);
+        left < rx2.saturating_mul(ry2)
+    }
+
+    /// Determines whether the midpoint `(x + 1/2, y)` lies outside of the ellipse.
+    const fn is_outside_right(&self, x: u128, y: u128) -> bool {
+        let (rx2, ry2) = self.squared_radii();
+        let left = ry2
+            .saturating_mul(x * (x + 1))
+            .saturating_add(rx2.saturating_mul(y * y))
+            .saturating_add(ry2 / 4);
+        let right = rx2.saturating_mul(ry2);
+
+        if ry2 % 4 == 0 {
+            left > right
+        } else {
+            left >= right
+        }
+    }
+
+    /// Returns the `y` that the first region chooses for the given `x`.
+    fn first_region_y(&self, x: u128) -> Option<u128> {
+        if x == 0 {
+            return Some(self.radius_y);
+        }
+
+        partition_point(self.radius_y + 1, |y| self.is_inside_below(x, y)).checked_sub(1)
+    }
+
+    /// Returns the `x` that the second region chooses for the given `y`.
+    fn second_region_x(&self, y: u128) -> u128 {
+        let (x, turn_y) = self.turn;
+
+        if y == turn_y {
+            return x;
+        }
+
+        x + partition_point(self.radius_x + 1 - x, |offset| {
+            !self.is_outside_right(x + offset, y)
+        })
+    }
+
+    fn row(&self, y: u128) -> QuadrantRow {
+        if self.radius_y == 0 {
+            return QuadrantRow {
+                point: Some(self.radius_x),
+                run: None,
+            };
+        }
+
+        let (turn_x, turn_y) = self.turn;
+        let first = partition_point(turn_x, |x| self.is_inside_below(x, y + 1));
+        let end = partition_point(turn_x, |x| self.is_inside_below(x, y));
+        QuadrantRow {
+            point: (y <= turn_y).then(|| self.second_region_x(y)),
+            run: (first < end).then(|| (first, end - 1)),
+        }
+    }
+}
+
+/// Adds the spans between pairs of crossings of the row through the cell centers with the polygon's edges,
+/// following the even-odd rule.
+fn scanline(vertices: &[Coordinate], y: usize, spans: &mut Vec<(i128, i128)>) {
+    let row = to_f64(y);
+    let mut crossings = Vec::new();
+
+    for (start, end) in vertices.iter().zip(vertices.iter().cycle().skip(1)) {
+        let (x0, y0) = (to_f64(start.x()), to_f64(start.y()));
+        let (x1, y1) = (to_f64(end.x()), to_f64(end.y()));
+
+        if (y0 <= row && row < y1) || (y1 <= row && row < y0) {
+            crossings.push(x0 + (row - y0) * (x1 - x0) / (y1 - y0));
+        }
+    }
+
+    crossings.sort_by(f64::total_cmp);
+
+    for pair in crossings.chunks_exact(2) {
+        let left = to_index(pair[0]);
+        let left = if to_f64(left) < pair[0] {
+            left + 1
+        } else {
+            left
+        };
+        spans.push((to_i128(left), to_i128(to_index(pair[1]))));
+    }
+}
+
+/// Adds the cells of the polygon's edges in the given row, which is within the bounds.
+fn edges(vertices: &[Coordinate], y: usize, bounds: &Rect, spans: &mut Vec<(i128, i128)>) {
+    let row = Rect::new(
+        (bounds.origin().x(), y),
+        bounds.width(),
+        NonZero::<usize>::MIN,
+    );
+
+    for (start, end) in vertices.iter().zip(vertices.iter().cycle().skip(1)) {
+        let mut columns = Bresenham::clipped(*start, *end, &row).map(|cell| cell.x());
+
+        if let Some(first) = columns.next() {
+            let last = columns.last().unwrap_or(first);
+            spans.push((to_i128(first.min(last)), to_i128(first.max(last))));
+        }
+    }
+}
+
+/// Clips the spans of a row to the bounds, sorts them and merges those that overlap or touch.
+fn merge(spans: Vec<(i128, i128)>, bounds: &Rect) -> Vec<(usize, usize)> {
+    let (left, right) = (bounds.origin().x(), bounds.bottom_right().x());
+    let mut spans: Vec<_> = spans
+        .into_iter()
+        .filter_map(|(first, last)| {
+            let first = usize::try_from(first.max(to_i128(left))).ok()?;
+            let last = usize::try_from(last.min(to_i128(right))).ok()?;
+            (first <= last).then_some((first, last))
+        })
+        .collect();
+
+    spans.sort_unstable();
+    spans.dedup_by(|next, previous| {
+        let touches = next.0 <= previous.1.saturating_add(1);
+
+        if touches {
+            previous.1 = previous.1.max(next.1);
+        }
+
+        touches
+    });
+    spans
+}
+
+const fn cell_center(coordinate: Coordinate) -> [f64; 2] {
+    [to_f64(coordinate.x()) + 0.5, to_f64(coordinate.y()) + 0.5]
+}
+
+fn coordinate_to_i128(coordinate: Coordinate) -> (i128, i128) {
+    (to_i128(coordinate.x()), to_i128(coordinate.y()))
+}
@@ -0,0 +1,63 @@
+use core::fmt::{self, Display, Formatter};
+
+/// One of the four sides of a cell on a grid.
+///
+/// The y axis points downwards, so [`Side::North`] is the side facing the row above.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Side {
+    /// The upper side of a cell.
+    North,
+    /// The right side of a cell.
+    East,
+    /// The lower side of a cell.
+    South,
+    /// The left side of a cell.
+    West,
+}
+
+impl Side {
+    /// All four sides in clockwise order, starting with [`Side::North`].
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Returns the side on the opposite of the cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Side;
+    ///
+    /// assert_eq!(Side::North.opposite(), Side::South);
+    /// assert_eq!(Side::East.opposite(), Side::West);
+    /// ```
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// Returns the `(dx, dy)` offset to the cell on the other side of this side.
+    #[must_use]
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::East => (1, 0),
+            Self::South => (0, 1),
+            Self::West => (-1, 0),
+        }
+    }
+}
+
+impl Display for Side {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::North => write!(f, "north"),
+            Self::East => write!(f, "east"),
+            Self::South => write!(f, "south"),
+            Self::West => write!(f, "west"),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::Coordinate;
+
+/// A search state that is located at a coordinate of a grid.
+///
+/// States can carry additional information, such as a heading or collected keys,
+/// so that the same cell can be visited in different states.
+pub trait State: Clone + Eq {
+    /// Returns the coordinate at which the state is located.
+    fn coordinate(&self) -> Coordinate;
+}
+
+impl State for Coordinate {
+    fn coordinate(&self) -> Coordinate {
+        *self
+    }
+}
+
+impl<T> State for (Coordinate, T)
+where
+    T: Clone + Eq,
+{
+    fn coordinate(&self) -> Coordinate {
+        self.0
+    }
+}
@@ -0,0 +1,173 @@
+use alloc::vec::Vec;
+
+use crate::{Grid, Transform, TransformedView};
+
+/// A mirror line that runs between two adjacent columns or rows of a grid.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MirrorAxis {
+    /// A vertical line left of the given column, mirroring the columns on both sides.
+    Vertical(usize),
+    /// A horizontal line above the given row, mirroring the rows on both sides.
+    Horizontal(usize),
+}
+
+impl<T> Grid<T>
+where
+    T: PartialEq,
+{
+    /// Returns the transforms under which the grid is unchanged, starting with [`Transform::Identity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Transform};
+    ///
+    /// let grid = Grid::try_from(("#.#...#.#".chars(), NonZero::new(3).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.symmetries(), Transform::ALL);
+    ///
+    /// let grid = Grid::try_from(("##....".chars(), NonZero::new(3).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.symmetries(), [Transform::Identity]);
+    ///
+    /// let grid = Grid::try_from(("#.#...".chars(), NonZero::new(3).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.symmetries(), [Transform::Identity, Transform::FlipHorizontal]);
+    /// ```
+    #[must_use]
+    pub fn symmetries(&self) -> Vec<Transform> {
+        Transform::ALL
+            .into_iter()
+            .filter(|transform| {
+                let view = self.transformed_view(*transform);
+                view.width() == self.width() && view.iter().eq(self.iter())
+            })
+            .collect()
+    }
+
+    /// Returns the number of mirrored pairs of cells that differ across the given axis.
+    ///
+    /// Only the rows or columns that have a counterpart on the other side are compared.
+    /// Returns `None` if the axis does not run between two columns or rows of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, MirrorAxis};
+    ///
+    /// let grid = Grid::try_from(("#..#.#..".chars(), NonZero::new(4).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.mirror_mismatches(MirrorAxis::Vertical(2)), Some(1));
+    /// assert_eq!(grid.mirror_mismatches(MirrorAxis::Vertical(1)), Some(2));
+    /// assert_eq!(grid.mirror_mismatches(MirrorAxis::Horizontal(1)), Some(3));
+    /// assert_eq!(grid.mirror_mismatches(MirrorAxis::Horizontal(2)), None);
+    /// ```
+    #[must_use]
+    pub fn mirror_mismatches(&self, axis: MirrorAxis) -> Option<usize> {
+        let (position, view) = match axis {
+            MirrorAxis::Vertical(column) => (column, self.transposed()),
+            MirrorAxis::Horizontal(row) => (row, self.transformed_view(Transform::Identity)),
+        };
+
+        (position > 0 && position < view.height().get()).then(|| mismatches_above(&view, position))
+    }
+
+    /// Returns the mirror axes across which exactly the given number of mirrored pairs of cells differ.
+    ///
+    /// Vertical axes are listed before horizontal axes. Pass zero to find exact reflections,
+    /// or a small number to find reflections that are only spoilt by a few cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, MirrorAxis};
+    ///
+    /// let grid = Grid::try_from((
+    ///     "o.oo..oo.\
+    ///      ..o.oo.o.\
+    ///      oo......o\
+    ///      oo......o\
+    ///      ..o.oo.o.\
+    ///      ..oo..oo.\
+    ///      o.o.oo.o.".chars(),
+    ///     NonZero::new(9).unwrap(),
+    /// ))
+    /// .unwrap();
+    ///
+    /// assert_eq!(grid.mirror_axes(0), [MirrorAxis::Vertical(5)]);
+    /// assert_eq!(grid.mirror_axes(1), [MirrorAxis::Horizontal(3)]);
+    /// ```
+    #[must_use]
+    pub fn mirror_axes(&self, mismatches: usize) -> Vec<MirrorAxis> {
+        let vertical = (1..self.width().get()).map(MirrorAxis::Vertical);
+        let horizontal = (1..self.height().get()).map(MirrorAxis::Horizontal);
+
+        vertical
+            .chain(horizontal)
+            .filter(|axis| self.mirror_mismatches(*axis) == Some(mismatches))
+            .collect()
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone + Ord,
+{
+    /// Returns the transform that maps the grid to its canonical form.
+    ///
+    /// See [`Grid::canonical`] for details.
+    #[must_use]
+    pub fn canonical_transform(&self) -> Transform {
+        Transform::ALL
+            .into_iter()
+            .min_by(|left, right| {
+                let (left, right) = (self.transformed_view(*left), self.transformed_view(*right));
+                (left.width(), left.height())
+                    .cmp(&(right.width(), right.height()))
+                    .then_with(|| left.iter().cmp(right.iter()))
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the canonical form of the grid under rotation and reflection.
+    ///
+    /// All grids that can be transformed into each other share the same canonical form,
+    /// which makes it suitable for deduplicating them. The canonical form is the transformed grid
+    /// with the smallest dimensions, followed by the smallest cells in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Transform};
+    ///
+    /// let grid = Grid::try_from(("ab.cd.".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let canonical = grid.canonical();
+    ///
+    /// assert_eq!(canonical.rows().map(Iterator::collect).collect::<Vec<String>>(), ["..", "bd", "ac"]);
+    ///
+    /// for transform in Transform::ALL {
+    ///     assert_eq!(grid.transformed(transform).canonical(), canonical);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn canonical(&self) -> Self {
+        self.transformed(self.canonical_transform())
+    }
+}
+
+/// Counts the differing pairs of cells mirrored across the line above the given row.
+fn mismatches_above<T>(view: &TransformedView<'_, T>, row: usize) -> usize
+where
+    T: PartialEq,
+{
+    let rows = row.min(view.height().get() - row);
+
+    (0..rows)
+        .flat_map(|offset| (0..view.width().get()).map(move |x| (x, offset)))
+        .filter(|(x, offset)| view.get((*x, row - 1 - offset)) != view.get((*x, row + offset)))
+        .count()
+}
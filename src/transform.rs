@@ -0,0 +1,268 @@
+use alloc::vec;
+use core::num::NonZero;
+
+use crate::{Coordinate, Grid};
+
+/// One of the eight symmetries of a square, which map a grid onto itself up to its dimensions.
+///
+/// The y axis points downwards, so rotations are clockwise as displayed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Transform {
+    /// Leaves the grid unchanged.
+    #[default]
+    Identity,
+    /// Rotates the grid by 90° clockwise.
+    Rotate90,
+    /// Rotates the grid by 180°.
+    Rotate180,
+    /// Rotates the grid by 270° clockwise, i.e. 90° counter-clockwise.
+    Rotate270,
+    /// Mirrors the grid horizontally, exchanging left and right.
+    FlipHorizontal,
+    /// Mirrors the grid vertically, exchanging top and bottom.
+    FlipVertical,
+    /// Mirrors the grid along the diagonal from the top left to the bottom right corner.
+    Transpose,
+    /// Mirrors the grid along the diagonal from the top right to the bottom left corner.
+    AntiTranspose,
+}
+
+impl Transform {
+    /// All eight transforms, starting with the four rotations.
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::Rotate90,
+        Self::Rotate180,
+        Self::Rotate270,
+        Self::FlipHorizontal,
+        Self::FlipVertical,
+        Self::Transpose,
+        Self::AntiTranspose,
+    ];
+
+    /// Returns the transform that applies this transform first and then `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Transform;
+    ///
+    /// assert_eq!(Transform::Rotate90.then(Transform::Rotate90), Transform::Rotate180);
+    /// assert_eq!(Transform::Rotate90.then(Transform::FlipHorizontal), Transform::Transpose);
+    /// assert_eq!(Transform::FlipHorizontal.then(Transform::Rotate90), Transform::AntiTranspose);
+    /// ```
+    #[must_use]
+    pub const fn then(self, next: Self) -> Self {
+        let (swap, flip_x, flip_y) = self.parts();
+        let (next_swap, next_flip_x, next_flip_y) = next.parts();
+
+        if next_swap {
+            Self::from_parts(!swap, next_flip_x ^ flip_y, next_flip_y ^ flip_x)
+        } else {
+            Self::from_parts(swap, next_flip_x ^ flip_x, next_flip_y ^ flip_y)
+        }
+    }
+
+    /// Returns the transform that reverts this transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid2d::Transform;
+    ///
+    /// assert_eq!(Transform::Rotate90.inverse(), Transform::Rotate270);
+    /// assert_eq!(Transform::Transpose.inverse(), Transform::Transpose);
+    ///
+    /// for transform in Transform::ALL {
+    ///     assert_eq!(transform.then(transform.inverse()), Transform::Identity);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        match self.parts() {
+            (true, flip_x, flip_y) => Self::from_parts(true, flip_y, flip_x),
+            (false, flip_x, flip_y) => Self::from_parts(false, flip_x, flip_y),
+        }
+    }
+
+    /// Returns true, if the transform exchanges width and height, else false.
+    #[must_use]
+    pub const fn swaps_axes(self) -> bool {
+        self.parts().0
+    }
+
+    /// Returns the width and height of a grid of the given dimensions after the transform.
+    #[must_use]
+    pub const fn dimensions(
+        self,
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+    ) -> (NonZero<usize>, NonZero<usize>) {
+        if self.swaps_axes() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// Maps a coordinate of a grid of the given dimensions to its position after the transform.
+    ///
+    /// Returns `None` if the coordinate is not on the grid.
+    /// Use the [`Transform::inverse`] with the transformed dimensions to map coordinates back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Transform};
+    ///
+    /// let (width, height) = (NonZero::new(3).unwrap(), NonZero::new(2).unwrap());
+    /// let coordinate = Coordinate::new(2, 0);
+    /// let rotated = Transform::Rotate90.map_coordinate(coordinate, width, height);
+    ///
+    /// assert_eq!(rotated, Some(Coordinate::new(1, 2)));
+    /// assert_eq!(
+    ///     Transform::Rotate270.map_coordinate(rotated.unwrap(), height, width),
+    ///     Some(coordinate)
+    /// );
+    /// assert_eq!(Transform::Rotate90.map_coordinate((3, 0), width, height), None);
+    /// ```
+    #[must_use]
+    pub fn map_coordinate(
+        self,
+        coordinate: impl TryInto<Coordinate>,
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+    ) -> Option<Coordinate> {
+        let (x, y) = coordinate.try_into().ok()?.into();
+
+        if x >= width.get() || y >= height.get() {
+            return None;
+        }
+
+        let (x, y) = self.map_unchecked(x, y, width, height);
+        Some(Coordinate::new(x, y))
+    }
+
+    /// Maps a position, which must be within the given dimensions.
+    pub(crate) const fn map_unchecked(
+        self,
+        x: usize,
+        y: usize,
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+    ) -> (usize, usize) {
+        let (swap, flip_x, flip_y) = self.parts();
+        let (width, height) = self.dimensions(width, height);
+        let (x, y) = if swap { (y, x) } else { (x, y) };
+        let x = if flip_x { width.get() - 1 - x } else { x };
+        let y = if flip_y { height.get() - 1 - y } else { y };
+        (x, y)
+    }
+
+    /// Decomposes the transform into an optional transposition followed by optional flips.
+    const fn parts(self) -> (bool, bool, bool) {
+        match self {
+            Self::Identity => (false, false, false),
+            Self::Rotate90 => (true, true, false),
+            Self::Rotate180 => (false, true, true),
+            Self::Rotate270 => (true, false, true),
+            Self::FlipHorizontal => (false, true, false),
+            Self::FlipVertical => (false, false, true),
+            Self::Transpose => (true, false, false),
+            Self::AntiTranspose => (true, true, true),
+        }
+    }
+
+    const fn from_parts(swap: bool, flip_x: bool, flip_y: bool) -> Self {
+        match (swap, flip_x, flip_y) {
+            (false, false, false) => Self::Identity,
+            (true, true, false) => Self::Rotate90,
+            (false, true, true) => Self::Rotate180,
+            (true, false, true) => Self::Rotate270,
+            (false, true, false) => Self::FlipHorizontal,
+            (false, false, true) => Self::FlipVertical,
+            (true, false, false) => Self::Transpose,
+            (true, true, true) => Self::AntiTranspose,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Applies the transform to the grid in place, exchanging width and height where needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Transform};
+    ///
+    /// let mut grid = Grid::try_from(("abcdef".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// grid.transform(Transform::Rotate90);
+    ///
+    /// assert_eq!(grid.rows().map(Iterator::collect).collect::<Vec<String>>(), ["da", "eb", "fc"]);
+    ///
+    /// grid.transform(Transform::FlipVertical);
+    ///
+    /// assert_eq!(grid.rows().map(Iterator::collect).collect::<Vec<String>>(), ["fc", "eb", "da"]);
+    /// ```
+    pub fn transform(&mut self, transform: Transform) {
+        let (width, height) = (self.width(), self.height());
+        let columns = transform.dimensions(width, height).0.get();
+        let target = |index: usize| {
+            let (x, y) =
+                transform.map_unchecked(index % width.get(), index / width.get(), width, height);
+            y * columns + x
+        };
+        let items: &mut [T] = self.as_mut();
+        let mut placed = vec![false; items.len()];
+
+        for start in 0..items.len() {
+            if placed[start] {
+                continue;
+            }
+
+            let mut index = target(start);
+
+            while index != start {
+                items.swap(start, index);
+                placed[index] = true;
+                index = target(index);
+            }
+
+            placed[start] = true;
+        }
+
+        if transform.swaps_axes() {
+            self.swap_dimensions();
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Returns a transformed copy of the grid.
+    ///
+    /// See [`Grid::transform`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Transform};
+    ///
+    /// let grid = Grid::try_from(("abcdef".chars(), NonZero::new(3).unwrap())).unwrap();
+    /// let transposed = grid.transformed(Transform::Transpose);
+    ///
+    /// assert_eq!(transposed.rows().map(Iterator::collect).collect::<Vec<String>>(), ["ad", "be", "cf"]);
+    /// assert_eq!(transposed.transformed(Transform::Transpose), grid);
+    /// ```
+    #[must_use]
+    pub fn transformed(&self, transform: Transform) -> Self {
+        let mut grid = self.clone();
+        grid.transform(transform);
+        grid
+    }
+}
@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::num::NonZero;
+
+use crate::{Coordinate, Grid, Transform};
+
+/// A read-only view of a [`Grid`] that presents it rotated, mirrored or transposed.
+///
+/// The cells are not moved, but looked up in the underlying grid on each access.
+/// Coordinates passed to and returned by the view are relative to the transformed frame.
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct TransformedView<'grid, T> {
+    grid: &'grid Grid<T>,
+    transform: Transform,
+}
+
+impl<T> Grid<T> {
+    /// Returns a view of the grid with the given transform applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Transform};
+    ///
+    /// let grid = Grid::try_from(("abcdef".chars(), NonZero::new(3).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.transformed_view(Transform::Rotate90).to_grid(), grid.transformed(Transform::Rotate90));
+    /// ```
+    #[must_use]
+    pub const fn transformed_view(&self, transform: Transform) -> TransformedView<'_, T> {
+        TransformedView {
+            grid: self,
+            transform,
+        }
+    }
+
+    /// Returns a view of the grid mirrored along its main diagonal.
+    #[must_use]
+    pub const fn transposed(&self) -> TransformedView<'_, T> {
+        self.transformed_view(Transform::Transpose)
+    }
+
+    /// Returns a view of the grid rotated by 90° clockwise.
+    #[must_use]
+    pub const fn rotated_cw(&self) -> TransformedView<'_, T> {
+        self.transformed_view(Transform::Rotate90)
+    }
+
+    /// Returns a view of the grid rotated by 90° counter-clockwise.
+    #[must_use]
+    pub const fn rotated_ccw(&self) -> TransformedView<'_, T> {
+        self.transformed_view(Transform::Rotate270)
+    }
+
+    /// Returns a view of the grid rotated by 180°.
+    #[must_use]
+    pub const fn rotated_180(&self) -> TransformedView<'_, T> {
+        self.transformed_view(Transform::Rotate180)
+    }
+
+    /// Returns a view of the grid with the x axis reversed, exchanging left and right.
+    #[must_use]
+    pub const fn flipped_x(&self) -> TransformedView<'_, T> {
+        self.transformed_view(Transform::FlipHorizontal)
+    }
+
+    /// Returns a view of the grid with the y axis reversed, exchanging top and bottom.
+    #[must_use]
+    pub const fn flipped_y(&self) -> TransformedView<'_, T> {
+        self.transformed_view(Transform::FlipVertical)
+    }
+}
+
+impl<'grid, T> TransformedView<'grid, T> {
+    /// Returns the transform that maps the underlying grid to the view.
+    #[must_use]
+    pub const fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Returns the width of the view.
+    #[must_use]
+    pub fn width(&self) -> NonZero<usize> {
+        self.dimensions().0
+    }
+
+    /// Returns the height of the view.
+    #[must_use]
+    pub fn height(&self) -> NonZero<usize> {
+        self.dimensions().1
+    }
+
+    /// Returns an Option to a reference of the cell content at the given coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let grid = Grid::try_from((0u8..6, NonZero::new(3).unwrap())).unwrap();
+    /// let view = grid.rotated_cw();
+    ///
+    /// assert_eq!((view.width().get(), view.height().get()), (2, 3));
+    /// assert_eq!(view.get((0, 0)), Some(&3));
+    /// assert_eq!(view.get((1, 2)), Some(&2));
+    /// assert_eq!(view.get((2, 0)), None);
+    /// ```
+    pub fn get(&self, coordinate: impl TryInto<Coordinate>) -> Option<&'grid T> {
+        let (width, height) = self.dimensions();
+        let coordinate = coordinate.try_into().ok()?;
+        self.grid.get(
+            self.transform
+                .inverse()
+                .map_coordinate(coordinate, width, height)?,
+        )
+    }
+
+    /// Yields the rows of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::Grid;
+    ///
+    /// let grid = Grid::try_from(("abcdef".chars(), NonZero::new(3).unwrap())).unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.transposed().rows().map(Iterator::collect).collect::<Vec<String>>(),
+    ///     ["ad", "be", "cf"]
+    /// );
+    /// assert_eq!(
+    ///     grid.flipped_x().rows().map(Iterator::collect).collect::<Vec<String>>(),
+    ///     ["cba", "fed"]
+    /// );
+    /// ```
+    pub fn rows(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &'grid T> + use<'grid, T>> + use<'grid, T> {
+        let view = *self;
+        (0..self.height().get()).map(move |y| (0..view.width().get()).map(move |x| view.cell(x, y)))
+    }
+
+    /// Yields references to the view's items in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'grid T> + use<'grid, T> {
+        self.rows().flatten()
+    }
+
+    /// Yields tuples of Coordinate and reference to the view's items.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Coordinate, &'grid T)> + use<'grid, T> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.enumerate()
+                .map(move |(x, item)| (Coordinate::new(x, y), item))
+        })
+    }
+
+    /// Returns a view with the given transform applied after the view's own transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Grid, Transform};
+    ///
+    /// let grid = Grid::try_from(("abcdef".chars(), NonZero::new(3).unwrap())).unwrap();
+    ///
+    /// assert_eq!(grid.rotated_cw().rotated_cw().transform(), Transform::Rotate180);
+    /// assert_eq!(grid.flipped_x().flipped_y().to_grid(), grid.rotated_180().to_grid());
+    /// ```
+    #[must_use]
+    pub const fn transformed(&self, transform: Transform) -> Self {
+        Self {
+            grid: self.grid,
+            transform: self.transform.then(transform),
+        }
+    }
+
+    /// Returns the view mirrored along its main diagonal.
+    #[must_use]
+    pub const fn transposed(&self) -> Self {
+        self.transformed(Transform::Transpose)
+    }
+
+    /// Returns the view rotated by 90° clockwise.
+    #[must_use]
+    pub const fn rotated_cw(&self) -> Self {
+        self.transformed(Transform::Rotate90)
+    }
+
+    /// Returns the view rotated by 90° counter-clockwise.
+    #[must_use]
+    pub const fn rotated_ccw(&self) -> Self {
+        self.transformed(Transform::Rotate270)
+    }
+
+    /// Returns the view rotated by 180°.
+    #[must_use]
+    pub const fn rotated_180(&self) -> Self {
+        self.transformed(Transform::Rotate180)
+    }
+
+    /// Returns the view with the x axis reversed, exchanging left and right.
+    #[must_use]
+    pub const fn flipped_x(&self) -> Self {
+        self.transformed(Transform::FlipHorizontal)
+    }
+
+    /// Returns the view with the y axis reversed, exchanging top and bottom.
+    #[must_use]
+    pub const fn flipped_y(&self) -> Self {
+        self.transformed(Transform::FlipVertical)
+    }
+
+    fn dimensions(&self) -> (NonZero<usize>, NonZero<usize>) {
+        self.transform
+            .dimensions(self.grid.width(), self.grid.height())
+    }
+
+    /// Returns the cell at the given position, which must be within the view.
+    fn cell(&self, x: usize, y: usize) -> &'grid T {
+        let (width, height) = self.dimensions();
+        let (x, y) = self.transform.inverse().map_unchecked(x, y, width, height);
+        &self.grid.as_ref()[y * self.grid.width().get() + x]
+    }
+}
+
+impl<T> TransformedView<'_, T>
+where
+    T: Clone,
+{
+    /// Copies the transformed grid into a new grid.
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T> {
+        let items: Vec<T> = self.iter().cloned().collect();
+        #[expect(unsafe_code)]
+        // SAFETY: The view yields exactly `width` items for each of its `height` rows.
+        unsafe {
+            Grid::new_unchecked(self.width(), items)
+        }
+    }
+}
+
+impl<T> Clone for TransformedView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TransformedView<'_, T> {}
+
+impl<T> Display for TransformedView<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for (x, element) in row.enumerate() {
+                if x > 0 {
+                    write!(f, "\t")?;
+                }
+
+                write!(f, "{element}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
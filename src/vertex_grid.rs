@@ -0,0 +1,146 @@
+use core::num::NonZero;
+
+use crate::{Coordinate, Corner, Grid};
+
+/// Data attached to the vertices between the cells of a grid, such as pillars or intersections.
+///
+/// Every cell has four vertices, one at each [`Corner`].
+/// Adjacent cells share the vertices between them, so the north-east vertex of a cell is
+/// the same as the north-west vertex of the cell to its right.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VertexGrid<V> {
+    width: NonZero<usize>,
+    height: NonZero<usize>,
+    vertices: Grid<V>,
+}
+
+impl<V> VertexGrid<V> {
+    /// Returns a new vertex grid for a grid of the given width and height.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the amount of vertices is too large to fit into a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use grid2d::{Coordinate, Corner, VertexGrid};
+    ///
+    /// let width = NonZero::new(3).unwrap();
+    /// let height = NonZero::new(2).unwrap();
+    /// let mut vertices = VertexGrid::new(width, height, || 0);
+    /// *vertices.get_mut(Coordinate::new(0, 0), Corner::SouthEast).unwrap() = 42;
+    ///
+    /// assert_eq!(vertices.get(Coordinate::new(1, 1), Corner::NorthWest), Some(&42));
+    /// assert_eq!(vertices.get(Coordinate::new(3, 0), Corner::NorthWest), None);
+    /// assert_eq!(vertices.vertices().width().get(), 4);
+    /// assert_eq!(vertices.vertices().height().get(), 3);
+    /// ```
+    pub fn new(width: NonZero<usize>, height: NonZero<usize>, initializer: impl Fn() -> V) -> Self {
+        Self::try_new(width, height, initializer).expect("vertex grid too large")
+    }
+
+    /// Returns a new vertex grid for a grid of the given width and height.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `None` if the amount of vertices is too large to fit into a `usize`.
+    pub fn try_new(
+        width: NonZero<usize>,
+        height: NonZero<usize>,
+        initializer: impl Fn() -> V,
+    ) -> Option<Self> {
+        Grid::try_new(width.checked_add(1)?, height.checked_add(1)?, initializer).map(|vertices| {
+            Self {
+                width,
+                height,
+                vertices,
+            }
+        })
+    }
+
+    /// Returns a new vertex grid matching the dimensions of the given grid.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the amount of vertices is too large to fit into a `usize`.
+    pub fn for_grid<T>(grid: &Grid<T>, initializer: impl Fn() -> V) -> Self {
+        Self::new(grid.width(), grid.height(), initializer)
+    }
+
+    /// Returns the width of the grid of cells.
+    #[must_use]
+    pub const fn width(&self) -> NonZero<usize> {
+        self.width
+    }
+
+    /// Returns the height of the grid of cells.
+    #[must_use]
+    pub const fn height(&self) -> NonZero<usize> {
+        self.height
+    }
+
+    /// Returns the vertices.
+    ///
+    /// The vertex at `(x, y)` is the north-west vertex of the cell at `(x, y)`.
+    #[must_use]
+    pub const fn vertices(&self) -> &Grid<V> {
+        &self.vertices
+    }
+
+    /// Returns an Option to a reference of the vertex at the given corner of a cell.
+    pub fn get(&self, coordinate: impl TryInto<Coordinate>, corner: Corner) -> Option<&V> {
+        self.vertex_coordinate(coordinate.try_into().ok()?, corner)
+            .and_then(|vertex| self.vertices.get(vertex))
+    }
+
+    /// Returns an Option to a mutable reference of the vertex at the given corner of a cell.
+    pub fn get_mut(
+        &mut self,
+        coordinate: impl TryInto<Coordinate>,
+        corner: Corner,
+    ) -> Option<&mut V> {
+        self.vertex_coordinate(coordinate.try_into().ok()?, corner)
+            .and_then(|vertex| self.vertices.get_mut(vertex))
+    }
+
+    /// Yields tuples of the corners and references to the vertices of the given cell.
+    ///
+    /// Yields nothing if the cell is not on the grid.
+    pub fn corners(
+        &self,
+        coordinate: impl TryInto<Coordinate>,
+    ) -> impl Iterator<Item = (Corner, &V)> {
+        let coordinate = coordinate.try_into().ok();
+        Corner::ALL
+            .into_iter()
+            .filter_map(move |corner| self.get(coordinate?, corner).map(|vertex| (corner, vertex)))
+    }
+
+    /// Determines whether the given cell coordinate is on the grid.
+    pub fn encompasses(&self, coordinate: impl TryInto<Coordinate>) -> bool {
+        coordinate.try_into().is_ok_and(|coordinate: Coordinate| {
+            coordinate.x() < self.width().get() && coordinate.y() < self.height().get()
+        })
+    }
+
+    fn vertex_coordinate(&self, coordinate: Coordinate, corner: Corner) -> Option<Coordinate> {
+        if !self.encompasses(coordinate) {
+            return None;
+        }
+
+        let (dx, dy) = corner.offset();
+        Some(Coordinate::new(coordinate.x() + dx, coordinate.y() + dy))
+    }
+}
+
+impl<V> VertexGrid<V>
+where
+    V: Default,
+{
+    /// Returns a new vertex grid for a type that implements the Default trait.
+    pub fn new_default(width: NonZero<usize>, height: NonZero<usize>) -> Self {
+        Self::new(width, height, V::default)
+    }
+}